@@ -16,6 +16,8 @@ use pylon_token::airdrop::{
     ConfigResponse, ExecuteMsg, InstantiateMsg, IsClaimedResponse, LatestStageResponse,
     MerkleRootResponse, MigrateMsg, QueryMsg,
 };
+use pylon_token::common::PendingOwnerResponse;
+use pylon_token::ownership::Ownership;
 use sha3::Digest;
 use std::convert::TryInto;
 
@@ -30,6 +32,7 @@ pub fn instantiate(
         deps.storage,
         &Config {
             owner: deps.api.addr_canonicalize(&msg.owner)?,
+            pending_owner: None,
             pylon_token: deps.api.addr_canonicalize(&msg.pylon_token)?,
         },
     )?;
@@ -43,12 +46,16 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::UpdateConfig { owner } => update_config(deps, info, owner),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
+            propose_new_owner(deps, env, info, owner, expires_in)
+        }
+        ExecuteMsg::AcceptOwnership {} => accept_ownership(deps, env, info),
+        ExecuteMsg::CancelOwnershipProposal {} => cancel_ownership_proposal(deps, info),
         ExecuteMsg::RegisterMerkleRoot { merkle_root } => {
             register_merkle_root(deps, info, merkle_root)
         }
@@ -61,22 +68,53 @@ pub fn execute(
     }
 }
 
-pub fn update_config(
+pub fn propose_new_owner(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    owner: Option<String>,
+    owner: String,
+    expires_in: Option<u64>,
 ) -> Result<Response, ContractError> {
     let mut config: Config = read_config(deps.storage)?;
-    if deps.api.addr_canonicalize(info.sender.as_str())? != config.owner {
-        return Err(ContractError::Unauthorized {});
+    let response = Ownership {
+        owner: &mut config.owner,
+        pending_owner: &mut config.pending_owner,
     }
+    .propose(deps.api, &env, &info, owner, expires_in)?;
 
-    if let Some(owner) = owner {
-        config.owner = deps.api.addr_canonicalize(&owner)?;
+    store_config(deps.storage, &config)?;
+    Ok(response)
+}
+
+pub fn accept_ownership(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut config: Config = read_config(deps.storage)?;
+    let response = Ownership {
+        owner: &mut config.owner,
+        pending_owner: &mut config.pending_owner,
     }
+    .accept(deps.api, &env, &info)?;
 
     store_config(deps.storage, &config)?;
-    Ok(Response::new().add_attributes(vec![("action", "update_config")]))
+    Ok(response)
+}
+
+pub fn cancel_ownership_proposal(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut config: Config = read_config(deps.storage)?;
+    let response = Ownership {
+        owner: &mut config.owner,
+        pending_owner: &mut config.pending_owner,
+    }
+    .cancel(deps.api, &info)?;
+
+    store_config(deps.storage, &config)?;
+    Ok(response)
 }
 
 pub fn register_merkle_root(
@@ -208,6 +246,13 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     let state = read_config(deps.storage)?;
     let resp = ConfigResponse {
         owner: deps.api.addr_humanize(&state.owner)?.to_string(),
+        pending_owner: match state.pending_owner {
+            Some(pending_owner) => Some(PendingOwnerResponse {
+                owner: deps.api.addr_humanize(&pending_owner.owner)?.to_string(),
+                expires_at: pending_owner.expires_at,
+            }),
+            None => None,
+        },
         pylon_token: deps.api.addr_humanize(&state.pylon_token)?.to_string(),
     };
 
@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use pylon_token::ownership::OwnershipError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -20,4 +21,25 @@ pub enum ContractError {
 
     #[error("Unauthorized")]
     Unauthorized {},
+
+    #[error("No pending owner")]
+    NoPendingOwner {},
+
+    #[error("Ownership proposal has expired")]
+    OwnershipProposalExpired {},
+
+    #[error("Ownership proposal expiry is out of range")]
+    InvalidOwnershipExpiry {},
+}
+
+impl From<OwnershipError> for ContractError {
+    fn from(e: OwnershipError) -> Self {
+        match e {
+            OwnershipError::Std(e) => ContractError::Std(e),
+            OwnershipError::Unauthorized => ContractError::Unauthorized {},
+            OwnershipError::NoPendingOwner => ContractError::NoPendingOwner {},
+            OwnershipError::OwnershipProposalExpired => ContractError::OwnershipProposalExpired {},
+            OwnershipError::InvalidExpiry => ContractError::InvalidOwnershipExpiry {},
+        }
+    }
 }
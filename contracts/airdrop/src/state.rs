@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use cosmwasm_std::{CanonicalAddr, StdResult, Storage};
 use cosmwasm_storage::{singleton, singleton_read, Bucket, ReadonlyBucket};
+use pylon_token::ownership::PendingOwner;
 
 static KEY_CONFIG: &[u8] = b"config";
 static KEY_LATEST_STAGE: &[u8] = b"latest_stage";
@@ -13,6 +14,7 @@ static PREFIX_CLAIM_INDEX: &[u8] = b"claim_index";
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
     pub owner: CanonicalAddr,
+    pub pending_owner: Option<PendingOwner>,
     pub pylon_token: CanonicalAddr,
 }
 
@@ -1,12 +1,13 @@
 use crate::contract::{execute, instantiate, query};
 use crate::error::ContractError;
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-use cosmwasm_std::{attr, from_binary, to_binary, CosmosMsg, SubMsg, Uint128, WasmMsg};
+use cosmwasm_std::{attr, from_binary, to_binary, Binary, CosmosMsg, SubMsg, Uint128, WasmMsg};
 use cw20::Cw20ExecuteMsg;
 use pylon_token::airdrop::{
     ConfigResponse, ExecuteMsg, InstantiateMsg, IsClaimedResponse, LatestStageResponse,
     MerkleRootResponse, QueryMsg,
 };
+use pylon_token::common::PendingOwnerResponse;
 
 #[test]
 fn proper_initialization() {
@@ -45,29 +46,195 @@ fn update_config() {
     let info = mock_info("addr0000", &[]);
     let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
+    // owner can no longer be replaced in a single step
+    let res = from_binary::<ExecuteMsg>(&Binary::from(
+        br#"{"update_config":{"owner":"owner0001"}}"#.to_vec(),
+    ));
+    assert!(res.is_err());
+
     // update owner
     let info = mock_info("owner0000", &[]);
-    let msg = ExecuteMsg::UpdateConfig {
-        owner: Some("owner0001".to_string()),
+    let msg = ExecuteMsg::ProposeNewOwner {
+        owner: "owner0001".to_string(),
+        expires_in: None,
     };
+    let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
-    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
-    assert_eq!(0, res.messages.len());
+    let info = mock_info("owner0001", &[]);
+    let msg = ExecuteMsg::AcceptOwnership {};
+    let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
     // it worked, let's query the state
     let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
     let config: ConfigResponse = from_binary(&res).unwrap();
     assert_eq!("owner0001", config.owner.as_str());
+    assert_eq!("anchor0000", config.pylon_token.as_str());
+    assert_eq!(None, config.pending_owner);
 
     // Unauthorzied err
     let info = mock_info("owner0000", &[]);
-    let msg = ExecuteMsg::UpdateConfig { owner: None };
+    let msg = ExecuteMsg::RegisterMerkleRoot {
+        merkle_root: "634de21cde1044f41d90373733b0f0fb1c1c71f9652b905cdf159e73c4cf0d37".to_string(),
+    };
+
+    let res = execute(deps.as_mut(), mock_env(), info, msg);
+    match res {
+        Err(ContractError::Unauthorized {}) => {}
+        _ => panic!("Must return unauthorized error"),
+    }
+}
+
+#[test]
+fn transfer_ownership() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        owner: "owner0000".to_string(),
+        pylon_token: "anchor0000".to_string(),
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // propose new owner
+    let info = mock_info("owner0000", &[]);
+    let msg = ExecuteMsg::ProposeNewOwner {
+        owner: "owner0001".to_string(),
+        expires_in: Some(100),
+    };
+
+    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert_eq!(0, res.messages.len());
+
+    // owner is not changed until the proposal is accepted
+    let env = mock_env();
+    let res = query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap();
+    let config: ConfigResponse = from_binary(&res).unwrap();
+    assert_eq!("owner0000", config.owner.as_str());
+    assert_eq!(
+        config.pending_owner,
+        Some(PendingOwnerResponse {
+            owner: "owner0001".to_string(),
+            expires_at: Some(env.block.time.seconds() + 100),
+        })
+    );
+
+    // Unauthorized err
+    let info = mock_info("owner0002", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::AcceptOwnership {},
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => {}
+        _ => panic!("Must return unauthorized error"),
+    }
+
+    // Expired err
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(101);
+    let info = mock_info("owner0001", &[]);
+    let res = execute(deps.as_mut(), env, info, ExecuteMsg::AcceptOwnership {});
+    match res {
+        Err(ContractError::OwnershipProposalExpired {}) => {}
+        _ => panic!("Must return expired error"),
+    }
+
+    // accept ownership
+    let info = mock_info("owner0001", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::AcceptOwnership {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "accept_ownership"),
+            attr("owner", "owner0001")
+        ]
+    );
+
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    let config: ConfigResponse = from_binary(&res).unwrap();
+    assert_eq!("owner0001", config.owner.as_str());
+    assert_eq!(None, config.pending_owner);
+
+    // Unauthorized err
+    let info = mock_info("owner0000", &[]);
+    let msg = ExecuteMsg::ProposeNewOwner {
+        owner: "owner0000".to_string(),
+        expires_in: None,
+    };
+
+    let res = execute(deps.as_mut(), mock_env(), info, msg);
+    match res {
+        Err(ContractError::Unauthorized {}) => {}
+        _ => panic!("Must return unauthorized error"),
+    }
+}
+
+#[test]
+fn cancel_ownership_proposal() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        owner: "owner0000".to_string(),
+        pylon_token: "anchor0000".to_string(),
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // nothing to cancel
+    let info = mock_info("owner0000", &[]);
+    let msg = ExecuteMsg::CancelOwnershipProposal {};
+    let res = execute(deps.as_mut(), mock_env(), info, msg);
+    match res {
+        Err(ContractError::NoPendingOwner {}) => {}
+        _ => panic!("Must return no pending owner error"),
+    }
+
+    let info = mock_info("owner0000", &[]);
+    let msg = ExecuteMsg::ProposeNewOwner {
+        owner: "owner0001".to_string(),
+        expires_in: None,
+    };
+    let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
+    // Unauthorized err
+    let info = mock_info("owner0001", &[]);
+    let msg = ExecuteMsg::CancelOwnershipProposal {};
     let res = execute(deps.as_mut(), mock_env(), info, msg);
     match res {
         Err(ContractError::Unauthorized {}) => {}
         _ => panic!("Must return unauthorized error"),
     }
+
+    let info = mock_info("owner0000", &[]);
+    let msg = ExecuteMsg::CancelOwnershipProposal {};
+    let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    let config: ConfigResponse = from_binary(&res).unwrap();
+    assert_eq!("owner0000", config.owner.as_str());
+    assert_eq!(None, config.pending_owner);
+
+    let info = mock_info("owner0001", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::AcceptOwnership {},
+    );
+    match res {
+        Err(ContractError::NoPendingOwner {}) => {}
+        _ => panic!("Must return no pending owner error"),
+    }
 }
 
 #[test]
@@ -8,7 +8,9 @@ use cosmwasm_std::{
     Uint128, WasmMsg,
 };
 
+use pylon_token::common::PendingOwnerResponse;
 use pylon_token::community::{ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use pylon_token::ownership::Ownership;
 
 use cw20::Cw20ExecuteMsg;
 
@@ -23,6 +25,7 @@ pub fn instantiate(
         deps.storage,
         &Config {
             gov_contract: deps.api.addr_canonicalize(&msg.gov_contract)?,
+            pending_gov_contract: None,
             pylon_token: deps.api.addr_canonicalize(&msg.pylon_token)?,
             spend_limit: msg.spend_limit,
         },
@@ -32,15 +35,15 @@ pub fn instantiate(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn execute(
-    deps: DepsMut,
-    _env: Env,
-    info: MessageInfo,
-    msg: ExecuteMsg,
-) -> StdResult<Response> {
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
     match msg {
         ExecuteMsg::UpdateConfig { spend_limit } => update_config(deps, info, spend_limit),
         ExecuteMsg::Spend { recipient, amount } => spend(deps, info, recipient, amount),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
+            propose_new_owner(deps, env, info, owner, expires_in)
+        }
+        ExecuteMsg::AcceptOwnership {} => accept_ownership(deps, env, info),
+        ExecuteMsg::CancelOwnershipProposal {} => cancel_ownership_proposal(deps, info),
     }
 }
 
@@ -63,6 +66,51 @@ pub fn update_config(
     Ok(Response::new().add_attributes(vec![("action", "update_config")]))
 }
 
+pub fn propose_new_owner(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    expires_in: Option<u64>,
+) -> StdResult<Response> {
+    let mut config: Config = read_config(deps.storage)?;
+    let response = Ownership {
+        owner: &mut config.gov_contract,
+        pending_owner: &mut config.pending_gov_contract,
+    }
+    .propose(deps.api, &env, &info, owner, expires_in)?;
+
+    store_config(deps.storage, &config)?;
+
+    Ok(response)
+}
+
+pub fn accept_ownership(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Response> {
+    let mut config: Config = read_config(deps.storage)?;
+    let response = Ownership {
+        owner: &mut config.gov_contract,
+        pending_owner: &mut config.pending_gov_contract,
+    }
+    .accept(deps.api, &env, &info)?;
+
+    store_config(deps.storage, &config)?;
+
+    Ok(response)
+}
+
+pub fn cancel_ownership_proposal(deps: DepsMut, info: MessageInfo) -> StdResult<Response> {
+    let mut config: Config = read_config(deps.storage)?;
+    let response = Ownership {
+        owner: &mut config.gov_contract,
+        pending_owner: &mut config.pending_gov_contract,
+    }
+    .cancel(deps.api, &info)?;
+
+    store_config(deps.storage, &config)?;
+
+    Ok(response)
+}
+
 /// Spend
 /// Owner can execute spend operation to send
 /// `amount` of ANC token to `recipient` for community purpose
@@ -109,6 +157,16 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     let state = read_config(deps.storage)?;
     let resp = ConfigResponse {
         gov_contract: deps.api.addr_humanize(&state.gov_contract)?.to_string(),
+        pending_gov_contract: match state.pending_gov_contract {
+            Some(pending_gov_contract) => Some(PendingOwnerResponse {
+                owner: deps
+                    .api
+                    .addr_humanize(&pending_gov_contract.owner)?
+                    .to_string(),
+                expires_at: pending_gov_contract.expires_at,
+            }),
+            None => None,
+        },
         pylon_token: deps.api.addr_humanize(&state.pylon_token)?.to_string(),
         spend_limit: state.spend_limit,
     };
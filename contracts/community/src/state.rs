@@ -3,14 +3,16 @@ use serde::{Deserialize, Serialize};
 
 use cosmwasm_std::{CanonicalAddr, StdResult, Storage, Uint128};
 use cosmwasm_storage::{singleton, singleton_read};
+use pylon_token::ownership::PendingOwner;
 
 static KEY_CONFIG: &[u8] = b"config";
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
     pub gov_contract: CanonicalAddr, // anchor gov address
-    pub pylon_token: CanonicalAddr,  // anchor token address
-    pub spend_limit: Uint128,        // spend limit per each `spend` request
+    pub pending_gov_contract: Option<PendingOwner>,
+    pub pylon_token: CanonicalAddr, // anchor token address
+    pub spend_limit: Uint128,       // spend limit per each `spend` request
 }
 
 pub fn store_config(storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
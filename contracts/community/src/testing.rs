@@ -3,6 +3,7 @@ use crate::contract::{execute, instantiate, query};
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 use cosmwasm_std::{from_binary, to_binary, CosmosMsg, StdError, SubMsg, Uint128, WasmMsg};
 use cw20::Cw20ExecuteMsg;
+use pylon_token::common::PendingOwnerResponse;
 use pylon_token::community::{ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
 
 #[test]
@@ -69,12 +70,142 @@ fn update_config() {
         config,
         ConfigResponse {
             gov_contract: "gov".to_string(),
+            pending_gov_contract: None,
             pylon_token: "pylon".to_string(),
             spend_limit: Uint128::from(500000u128),
         }
     );
 }
 
+#[test]
+fn transfer_ownership() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        gov_contract: "gov".to_string(),
+        pylon_token: "pylon".to_string(),
+        spend_limit: Uint128::from(1000000u128),
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let msg = ExecuteMsg::ProposeNewOwner {
+        owner: "gov0001".to_string(),
+        expires_in: Some(100u64),
+    };
+    let info = mock_info("addr0000", &[]);
+    let res = execute(deps.as_mut(), mock_env(), info, msg.clone());
+    match res {
+        Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "unauthorized"),
+        _ => panic!("DO NOT ENTER HERE"),
+    }
+
+    let info = mock_info("gov", &[]);
+    let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let env = mock_env();
+    let config: ConfigResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!("gov", config.gov_contract.as_str());
+    assert_eq!(
+        config.pending_gov_contract,
+        Some(PendingOwnerResponse {
+            owner: "gov0001".to_string(),
+            expires_at: Some(env.block.time.seconds() + 100u64),
+        })
+    );
+
+    // proposal expired
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(101u64);
+    let info = mock_info("gov0001", &[]);
+    let res = execute(deps.as_mut(), env, info, ExecuteMsg::AcceptOwnership {});
+    match res {
+        Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "ownership proposal expired"),
+        _ => panic!("DO NOT ENTER HERE"),
+    }
+
+    let info = mock_info("gov0001", &[]);
+    let _res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::AcceptOwnership {},
+    )
+    .unwrap();
+
+    let config: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!("gov0001", config.gov_contract.as_str());
+    assert_eq!(None, config.pending_gov_contract);
+
+    // previous gov contract lost its privilege
+    let msg = ExecuteMsg::UpdateConfig {
+        spend_limit: Some(Uint128::from(500000u128)),
+    };
+    let info = mock_info("gov", &[]);
+    let res = execute(deps.as_mut(), mock_env(), info, msg);
+    match res {
+        Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "unauthorized"),
+        _ => panic!("DO NOT ENTER HERE"),
+    }
+}
+
+#[test]
+fn cancel_ownership_proposal() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        gov_contract: "gov".to_string(),
+        pylon_token: "pylon".to_string(),
+        spend_limit: Uint128::from(1000000u128),
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let msg = ExecuteMsg::ProposeNewOwner {
+        owner: "gov0001".to_string(),
+        expires_in: None,
+    };
+    let info = mock_info("gov", &[]);
+    let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let info = mock_info("gov0001", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::CancelOwnershipProposal {},
+    );
+    match res {
+        Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "unauthorized"),
+        _ => panic!("DO NOT ENTER HERE"),
+    }
+
+    let info = mock_info("gov", &[]);
+    let _res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::CancelOwnershipProposal {},
+    )
+    .unwrap();
+
+    let info = mock_info("gov0001", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::AcceptOwnership {},
+    );
+    match res {
+        Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "no pending owner"),
+        _ => panic!("DO NOT ENTER HERE"),
+    }
+}
+
 #[test]
 fn test_spend() {
     let mut deps = mock_dependencies(&[]);
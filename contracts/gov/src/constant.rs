@@ -3,6 +3,12 @@ pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub const POLL_EXECUTE_REPLY_ID: u64 = 1;
 
+// multiple-choice polls
+pub const MAX_POLL_OPTIONS: usize = 10;
+
+// share rate checkpoints are recorded at most once in this many seconds
+pub const SHARE_RATE_CHECKPOINT_INTERVAL: u64 = 86400;
+
 // pagination
 pub const MAX_QUERY_LIMIT: u32 = 100;
 pub const DEFAULT_QUERY_LIMIT: u32 = 50;
@@ -33,7 +33,6 @@ pub fn execute(
     match msg {
         ExecuteMsg::Receive(msg) => executions::receive(deps, env, info, msg),
         ExecuteMsg::UpdateConfig {
-            owner,
             quorum,
             threshold,
             voting_period,
@@ -42,8 +41,8 @@ pub fn execute(
             snapshot_period,
         } => executions::update_config(
             deps,
+            env,
             info,
-            owner,
             quorum,
             threshold,
             voting_period,
@@ -51,6 +50,11 @@ pub fn execute(
             proposal_deposit,
             snapshot_period,
         ),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
+            executions::propose_new_owner(deps, env, info, owner, expires_in)
+        }
+        ExecuteMsg::AcceptOwnership {} => executions::accept_ownership(deps, env, info),
+        ExecuteMsg::CancelOwnershipProposal {} => executions::cancel_ownership_proposal(deps, info),
         ExecuteMsg::Poll(msg) => match msg {
             PollMsg::CastVote {
                 poll_id,
@@ -105,6 +109,17 @@ pub fn execute(
                 reward_token,
                 reward_amount,
             ),
+            AirdropMsg::TopUp { airdrop_id, amount } => {
+                executions::airdrop::top_up(deps, env, info, airdrop_id, amount)
+            }
+            AirdropMsg::Extend {
+                airdrop_id,
+                additional_period,
+            } => executions::airdrop::extend(deps, env, info, airdrop_id, additional_period),
+            AirdropMsg::Cancel {
+                airdrop_id,
+                refund_to,
+            } => executions::airdrop::cancel(deps, env, info, airdrop_id, refund_to),
             AirdropMsg::Allocate {
                 airdrop_id,
                 recipient,
@@ -129,10 +144,27 @@ pub fn execute(
                 recipient,
                 deallocate_amount,
             ),
+            AirdropMsg::AllocateBatch {
+                airdrop_id,
+                allocations,
+            } => executions::airdrop::allocate_batch(deps, env, info, airdrop_id, allocations),
+            AirdropMsg::DeallocateBatch {
+                airdrop_id,
+                deallocations,
+            } => executions::airdrop::deallocate_batch(deps, env, info, airdrop_id, deallocations),
             AirdropMsg::Update { target } => executions::airdrop::update(deps, env, info, target),
-            AirdropMsg::Claim { target } => executions::airdrop::claim(deps, env, info, target),
-            AirdropMsg::ClaimInternal { sender, airdrop_id } => {
-                executions::airdrop::claim_internal(deps, env, info, sender, airdrop_id)
+            AirdropMsg::Compact {} => executions::airdrop::compact(deps, env, info),
+            AirdropMsg::Claim {
+                target,
+                airdrop_ids,
+                recipient,
+            } => executions::airdrop::claim(deps, env, info, target, airdrop_ids, recipient),
+            AirdropMsg::ClaimInternal {
+                sender,
+                airdrop_id,
+                recipient,
+            } => {
+                executions::airdrop::claim_internal(deps, env, info, sender, airdrop_id, recipient)
             }
         },
     }
@@ -154,7 +186,17 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
     match msg {
         QueryMsg::ApiVersion {} => queries::query_api_version(deps),
         QueryMsg::Config {} => queries::config::query_config(deps),
-        QueryMsg::State {} => queries::state::query_state(deps),
+        QueryMsg::ConfigHistory {
+            start_after,
+            limit,
+            order_by,
+        } => queries::config::query_config_history(deps, start_after, limit, order_by),
+        QueryMsg::State {} => queries::state::query_state(deps, env),
+        QueryMsg::ShareRateHistory {
+            start_after,
+            limit,
+            order_by,
+        } => queries::state::query_share_rate_history(deps, start_after, limit, order_by),
         QueryMsg::Staker { address } => queries::bank::query_staker(deps, env, address),
         QueryMsg::Stakers {
             start_after,
@@ -167,7 +209,25 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
             limit,
             order_by,
         } => queries::airdrop::query_airdrops(deps, start_after, limit, order_by),
+        QueryMsg::AirdropProjection {
+            address,
+            airdrop_id,
+            at_time,
+        } => queries::airdrop::query_airdrop_projection(deps, env, address, airdrop_id, at_time),
+        QueryMsg::AirdropAllocations {
+            airdrop_id,
+            start_after,
+            limit,
+            order_by,
+        } => queries::airdrop::query_airdrop_allocations(
+            deps,
+            airdrop_id,
+            start_after,
+            limit,
+            order_by,
+        ),
         QueryMsg::Poll { poll_id } => queries::poll::query_poll(deps, poll_id),
+        QueryMsg::PollTally { poll_id } => queries::poll::query_poll_tally(deps, env, poll_id),
         QueryMsg::Polls {
             status_filter,
             category_filter,
@@ -1,4 +1,5 @@
 use cosmwasm_std::{OverflowError, StdError};
+use pylon_token::ownership::OwnershipError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -12,6 +13,27 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+
+    #[error("quorum must be 0 to 1")]
+    InvalidQuorum {},
+
+    #[error("threshold must be 0 to 1")]
+    InvalidThreshold {},
+
+    #[error("voting_period must be greater than 0")]
+    InvalidVotingPeriod {},
+
+    #[error("timelock_period must be greater than 0")]
+    InvalidTimelockPeriod {},
+
+    #[error("snapshot_period must be less than voting_period")]
+    InvalidSnapshotPeriod {},
+
+    #[error("proposal_deposit must be greater than 0")]
+    InvalidProposalDeposit {},
+
     #[error("Asset mismatch")]
     AssetMismatch {},
 
@@ -45,6 +67,21 @@ pub enum ContractError {
     #[error("Airdrop does not exist")]
     AirdropNotFound {},
 
+    #[error("Airdrop has already finished")]
+    AirdropFinished {},
+
+    #[error("Airdrop reward is not funded")]
+    AirdropNotFunded {},
+
+    #[error("additional_period must be greater than 0")]
+    InvalidAirdropPeriod {},
+
+    #[error("Airdrop period overflows")]
+    AirdropPeriodOverflow {},
+
+    #[error("Allocation amount must be greater than 0")]
+    InvalidAllocationAmount {},
+
     #[error("Snapshot has already occurred")]
     SnapshotAlreadyOccurred {},
 
@@ -63,12 +100,51 @@ pub enum ContractError {
     #[error("User has already voted")]
     AlreadyVoted {},
 
+    #[error("Invalid vote option")]
+    InvalidVoteOption {},
+
+    #[error("Execute messages must be given per option")]
+    ExecuteDataPerOption {},
+
+    #[error("Too few options")]
+    TooFewPollOptions {},
+
+    #[error("Too many options")]
+    TooManyPollOptions {},
+
+    #[error("Option name too short")]
+    PollOptionNameTooShort {},
+
+    #[error("Option name too long")]
+    PollOptionNameTooLong {},
+
     #[error("Expire height has not been reached")]
     PollNotExpired {},
 
     #[error("Voting period has not expired")]
     PollVotingPeriod {},
 
+    #[error("No pending owner")]
+    NoPendingOwner {},
+
+    #[error("Ownership proposal has expired")]
+    OwnershipProposalExpired {},
+
+    #[error("Ownership proposal expiry is out of range")]
+    InvalidOwnershipExpiry {},
+
     #[error("Invalid Reply Id")]
     InvalidReplyId {},
 }
+
+impl From<OwnershipError> for ContractError {
+    fn from(e: OwnershipError) -> Self {
+        match e {
+            OwnershipError::Std(e) => ContractError::Std(e),
+            OwnershipError::Unauthorized => ContractError::Unauthorized {},
+            OwnershipError::NoPendingOwner => ContractError::NoPendingOwner {},
+            OwnershipError::OwnershipProposalExpired => ContractError::OwnershipProposalExpired {},
+            OwnershipError::InvalidExpiry => ContractError::InvalidOwnershipExpiry {},
+        }
+    }
+}
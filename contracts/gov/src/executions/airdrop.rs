@@ -1,13 +1,15 @@
 use cosmwasm_std::{
-    to_binary, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response, StdResult, Uint128, WasmMsg,
+    to_binary, Addr, Api, BankMsg, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Response, StdResult, Storage, Uint128, WasmMsg,
 };
 use cw20::Cw20ExecuteMsg;
 use pylon_token::gov_msg::{AirdropMsg, ExecuteMsg};
 use std::cmp::max;
+use terraswap::asset::AssetInfo;
 
 use crate::constant::MAX_QUERY_LIMIT;
 use crate::error::ContractError;
-use crate::executions::ExecuteResult;
+use crate::executions::{validate_address, ExecuteResult};
 use crate::states::airdrop;
 use crate::states::airdrop::Airdrop;
 use crate::states::bank::TokenManager;
@@ -16,11 +18,11 @@ use crate::states::state::State;
 
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     start: u64,
     period: u64,
-    reward_token: String,
+    reward_token: AssetInfo,
     reward_amount: Uint128,
 ) -> ExecuteResult {
     let response = Response::new().add_attribute("action", "airdrop_instantiate");
@@ -30,25 +32,33 @@ pub fn instantiate(
         return Err(ContractError::Unauthorized {});
     }
 
+    let reward_token = match reward_token {
+        AssetInfo::Token { contract_addr } => AssetInfo::Token {
+            contract_addr: deps.api.addr_validate(contract_addr.as_str())?.to_string(),
+        },
+        AssetInfo::NativeToken { denom } => AssetInfo::NativeToken { denom },
+    };
+    ensure_funded(deps.as_ref(), &env, &reward_token, reward_amount)?;
+
     let mut state = State::load(deps.storage)?;
     let airdrop_id = state.total_airdrop_count;
 
-    Airdrop::save(
-        deps.storage,
-        &airdrop_id,
-        &Airdrop {
-            config: airdrop::Config {
-                start,
-                period,
-                reward_token: deps.api.addr_validate(reward_token.as_str())?,
-                reward_rate: Decimal::from_ratio(reward_amount, period),
-            },
-            state: airdrop::State {
-                last_update_time: start,
-                reward_per_token_stored: Decimal::zero(),
-            },
+    let airdrop = Airdrop {
+        config: airdrop::Config {
+            start,
+            period,
+            reward_token: reward_token.clone(),
+            reward_rate: Decimal::from_ratio(reward_amount, period),
         },
-    )?;
+        state: airdrop::State {
+            last_update_time: start,
+            reward_per_token_stored: Decimal::zero(),
+            funded: reward_amount,
+            distributed: Uint128::zero(),
+        },
+    };
+    Airdrop::save(deps.storage, &airdrop_id, &airdrop)?;
+    airdrop::Reserve::update(deps.storage, Uint128::zero(), &airdrop)?;
 
     state.total_airdrop_count += 1;
     state.airdrop_update_candidates.push(airdrop_id);
@@ -57,14 +67,222 @@ pub fn instantiate(
 
     Ok(response.add_attributes(vec![
         ("airdrop_id", &airdrop_id.to_string()),
-        ("reward_token", &reward_token),
+        ("reward_token", &reward_token.to_string()),
         ("reward_amount", &reward_amount.to_string()),
     ]))
 }
 
+pub fn top_up(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    airdrop_id: u64,
+    amount: Uint128,
+) -> ExecuteResult {
+    let response = Response::new().add_attribute("action", "airdrop_top_up");
+
+    let config = Config::load(deps.storage)?;
+    if config.owner != deps.api.addr_canonicalize(info.sender.as_str())? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let state = State::load(deps.storage)?;
+    let mut airdrop = load_ongoing(deps.storage, &env, &state, &airdrop_id)?;
+    ensure_funded(deps.as_ref(), &env, &airdrop.config.reward_token, amount)?;
+
+    let remaining_period = airdrop.finish() - airdrop.state.last_update_time;
+    let remaining_reward = Uint128::from(remaining_period) * airdrop.config.reward_rate;
+
+    let reserved = airdrop.reserved();
+    airdrop.config.reward_rate = Decimal::from_ratio(remaining_reward + amount, remaining_period);
+    airdrop.state.funded += amount;
+
+    Airdrop::save(deps.storage, &airdrop_id, &airdrop)?;
+    airdrop::Reserve::update(deps.storage, reserved, &airdrop)?;
+
+    Ok(response.add_attributes(vec![
+        ("airdrop_id", &airdrop_id.to_string()),
+        ("amount", &amount.to_string()),
+        ("reward_rate", &airdrop.config.reward_rate.to_string()),
+    ]))
+}
+
+pub fn extend(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    airdrop_id: u64,
+    additional_period: u64,
+) -> ExecuteResult {
+    let response = Response::new().add_attribute("action", "airdrop_extend");
+
+    let config = Config::load(deps.storage)?;
+    if config.owner != deps.api.addr_canonicalize(info.sender.as_str())? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if additional_period == 0 {
+        return Err(ContractError::InvalidAirdropPeriod {});
+    }
+
+    let state = State::load(deps.storage)?;
+    let mut airdrop = load_ongoing(deps.storage, &env, &state, &airdrop_id)?;
+
+    let remaining_period = airdrop.finish() - airdrop.state.last_update_time;
+    let remaining_reward = Uint128::from(remaining_period) * airdrop.config.reward_rate;
+
+    // the new finish must fit as well
+    airdrop.config.period = airdrop
+        .config
+        .period
+        .checked_add(additional_period)
+        .filter(|period| airdrop.config.start.checked_add(*period).is_some())
+        .ok_or(ContractError::AirdropPeriodOverflow {})?;
+    airdrop.config.reward_rate =
+        Decimal::from_ratio(remaining_reward, remaining_period + additional_period);
+
+    Airdrop::save(deps.storage, &airdrop_id, &airdrop)?;
+
+    Ok(response.add_attributes(vec![
+        ("airdrop_id", &airdrop_id.to_string()),
+        ("period", &airdrop.config.period.to_string()),
+        ("reward_rate", &airdrop.config.reward_rate.to_string()),
+    ]))
+}
+
+pub fn cancel(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    airdrop_id: u64,
+    refund_to: Option<String>,
+) -> ExecuteResult {
+    let mut response = Response::new().add_attribute("action", "airdrop_cancel");
+
+    let config = Config::load(deps.storage)?;
+    if config.owner != deps.api.addr_canonicalize(info.sender.as_str())? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let refund_to = match refund_to {
+        Some(refund_to) => deps.api.addr_validate(refund_to.as_str())?,
+        None => info.sender,
+    };
+
+    let mut state = State::load(deps.storage)?;
+    let mut airdrop = load_ongoing(deps.storage, &env, &state, &airdrop_id)?;
+
+    // rewards accrued until now stay claimable since reward_per_token_stored is kept as is
+    let remaining_period = airdrop.finish() - airdrop.state.last_update_time;
+    let refund_amount = Uint128::from(remaining_period) * airdrop.config.reward_rate;
+
+    let reserved = airdrop.reserved();
+    airdrop.config.period = airdrop.state.last_update_time - airdrop.config.start;
+    airdrop.state.funded = airdrop.state.funded.saturating_sub(refund_amount);
+
+    Airdrop::save(deps.storage, &airdrop_id, &airdrop)?;
+    airdrop::Reserve::update(deps.storage, reserved, &airdrop)?;
+    release_fund(
+        deps.as_ref(),
+        &mut state,
+        &airdrop.config.reward_token,
+        reserved - airdrop.reserved(),
+    )?;
+    State::save(deps.storage, &state)?;
+
+    if !refund_amount.is_zero() {
+        response = response.add_message(transfer_msg(
+            &airdrop.config.reward_token,
+            &refund_to,
+            refund_amount,
+        )?);
+    }
+
+    Ok(response.add_attributes(vec![
+        ("airdrop_id", &airdrop_id.to_string()),
+        ("refund_to", &refund_to.to_string()),
+        ("refund_amount", &refund_amount.to_string()),
+        ("period", &airdrop.config.period.to_string()),
+    ]))
+}
+
+/// Brings the airdrop up to date so that its rate can be changed from now on
+fn load_ongoing(
+    storage: &mut dyn Storage,
+    env: &Env,
+    state: &State,
+    airdrop_id: &u64,
+) -> Result<Airdrop, ContractError> {
+    let airdrop = update_airdrop(storage, env, state, airdrop_id)?;
+    if airdrop.is_final() {
+        return Err(ContractError::AirdropFinished {});
+    }
+
+    Ok(airdrop)
+}
+
+/// Deposits voting tokens which only airdrops can reserve
+pub fn fund(deps: DepsMut, amount: Uint128) -> ExecuteResult {
+    let mut state = State::load(deps.storage)?;
+    state.airdrop_fund += amount;
+    State::save(deps.storage, &state)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "airdrop_fund"),
+        ("amount", &amount.to_string()),
+        ("airdrop_fund", &state.airdrop_fund.to_string()),
+    ]))
+}
+
+fn ensure_funded(
+    deps: Deps,
+    env: &Env,
+    reward_token: &AssetInfo,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let available = if is_voting_token(deps, reward_token)? {
+        // staked balance and poll deposits cannot be airdropped,
+        // which leaves the voting tokens deposited through FundAirdrop
+        State::load(deps.storage)?.airdrop_fund
+    } else {
+        reward_token.query_pool(&deps.querier, deps.api, env.contract.address.clone())?
+    };
+    let reserved = airdrop::Reserve::load(deps.storage, reward_token)?;
+
+    if available < reserved + amount {
+        return Err(ContractError::AirdropNotFunded {});
+    }
+
+    Ok(())
+}
+
+/// Takes voting tokens sent out by an airdrop out of the airdrop fund
+fn release_fund(
+    deps: Deps,
+    state: &mut State,
+    reward_token: &AssetInfo,
+    amount: Uint128,
+) -> StdResult<()> {
+    if is_voting_token(deps, reward_token)? {
+        state.airdrop_fund = state.airdrop_fund.saturating_sub(amount);
+    }
+
+    Ok(())
+}
+
+fn is_voting_token(deps: Deps, reward_token: &AssetInfo) -> StdResult<bool> {
+    Ok(match reward_token {
+        AssetInfo::Token { contract_addr } => {
+            Config::load(deps.storage)?.pylon_token
+                == deps.api.addr_canonicalize(contract_addr.as_str())?
+        }
+        AssetInfo::NativeToken { .. } => false,
+    })
+}
+
 pub fn allocate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     airdrop_id: u64,
     recipient: String,
@@ -77,20 +295,14 @@ pub fn allocate(
         return Err(ContractError::Unauthorized {});
     }
 
-    let mut airdrop_reward = airdrop::Reward::load(
+    allocate_reward(
         deps.storage,
-        &deps.api.addr_validate(recipient.as_str())?,
+        deps.api,
         &airdrop_id,
+        &recipient,
+        allocate_amount,
     )?;
-
-    airdrop_reward.reward += allocate_amount;
-
-    airdrop::Reward::save(
-        deps.storage,
-        &deps.api.addr_validate(recipient.as_str())?,
-        &airdrop_id,
-        &airdrop_reward,
-    )?;
+    fund_allocation(deps, &env, &airdrop_id, allocate_amount)?;
 
     Ok(response.add_attributes(vec![
         ("airdrop_id", &airdrop_id.to_string()),
@@ -114,100 +326,407 @@ pub fn deallocate(
         return Err(ContractError::Unauthorized {});
     }
 
-    let mut airdrop_reward = airdrop::Reward::load(
+    deallocate_reward(
         deps.storage,
-        &deps.api.addr_validate(recipient.as_str())?,
+        deps.api,
         &airdrop_id,
+        &recipient,
+        deallocate_amount,
     )?;
+    release_allocation(deps.storage, &airdrop_id, deallocate_amount)?;
 
-    if airdrop_reward.reward < deallocate_amount {
-        return Err(ContractError::InsufficientReward {});
+    Ok(response.add_attributes(vec![
+        ("airdrop_id", &airdrop_id.to_string()),
+        ("recipient", &recipient),
+        ("amount", &deallocate_amount.to_string()),
+    ]))
+}
+
+pub fn allocate_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    airdrop_id: u64,
+    allocations: Vec<(String, Uint128)>,
+) -> ExecuteResult {
+    let response = Response::new().add_attribute("action", "airdrop_allocate_batch");
+
+    let config = Config::load(deps.storage)?;
+    if config.owner != deps.api.addr_canonicalize(info.sender.as_str())? {
+        return Err(ContractError::Unauthorized {});
+    }
+    if allocations.is_empty() {
+        return Err(ContractError::DataShouldBeGiven {});
     }
-    airdrop_reward.reward -= deallocate_amount;
 
-    airdrop::Reward::save(
-        deps.storage,
-        &deps.api.addr_validate(recipient.as_str())?,
-        &airdrop_id,
-        &airdrop_reward,
-    )?;
+    let mut total_amount = Uint128::zero();
+    for (recipient, amount) in allocations.iter() {
+        if amount.is_zero() {
+            return Err(ContractError::InvalidAllocationAmount {});
+        }
+        allocate_reward(deps.storage, deps.api, &airdrop_id, recipient, *amount)?;
+        total_amount += *amount;
+    }
+    fund_allocation(deps, &env, &airdrop_id, total_amount)?;
 
     Ok(response.add_attributes(vec![
         ("airdrop_id", &airdrop_id.to_string()),
-        ("recipient", &recipient),
-        ("amount", &deallocate_amount.to_string()),
+        ("count", &allocations.len().to_string()),
+        ("total_amount", &total_amount.to_string()),
+    ]))
+}
+
+pub fn deallocate_batch(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    airdrop_id: u64,
+    deallocations: Vec<(String, Uint128)>,
+) -> ExecuteResult {
+    let response = Response::new().add_attribute("action", "airdrop_deallocate_batch");
+
+    let config = Config::load(deps.storage)?;
+    if config.owner != deps.api.addr_canonicalize(info.sender.as_str())? {
+        return Err(ContractError::Unauthorized {});
+    }
+    if deallocations.is_empty() {
+        return Err(ContractError::DataShouldBeGiven {});
+    }
+
+    let mut total_amount = Uint128::zero();
+    for (recipient, amount) in deallocations.iter() {
+        if amount.is_zero() {
+            return Err(ContractError::InvalidAllocationAmount {});
+        }
+        deallocate_reward(deps.storage, deps.api, &airdrop_id, recipient, *amount)?;
+        total_amount += *amount;
+    }
+    release_allocation(deps.storage, &airdrop_id, total_amount)?;
+
+    Ok(response.add_attributes(vec![
+        ("airdrop_id", &airdrop_id.to_string()),
+        ("count", &deallocations.len().to_string()),
+        ("total_amount", &total_amount.to_string()),
     ]))
 }
 
+fn allocate_reward(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    airdrop_id: &u64,
+    recipient: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    if Airdrop::may_load(storage, airdrop_id)?.is_none() {
+        return Err(ContractError::AirdropNotFound {});
+    }
+
+    let recipient = api.addr_validate(recipient)?;
+    let mut airdrop_reward = airdrop::Reward::load(storage, &recipient, airdrop_id)?;
+    airdrop_reward.reward += amount;
+    airdrop::Reward::save(storage, &recipient, airdrop_id, &airdrop_reward)?;
+
+    let recipient_raw = api.addr_canonicalize(recipient.as_str())?;
+    let mut allocation = airdrop::Allocation::load(storage, airdrop_id, &recipient_raw)?;
+    allocation.amount += amount;
+    airdrop::Allocation::save(storage, airdrop_id, &recipient_raw, &allocation)?;
+
+    Ok(())
+}
+
+/// Reserves the allocated reward on top of the reward distributed by staking
+fn fund_allocation(
+    deps: DepsMut,
+    env: &Env,
+    airdrop_id: &u64,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let mut airdrop = Airdrop::load(deps.storage, airdrop_id)?;
+    ensure_funded(deps.as_ref(), env, &airdrop.config.reward_token, amount)?;
+
+    let reserved = airdrop.reserved();
+    airdrop.state.funded += amount;
+    Airdrop::save(deps.storage, airdrop_id, &airdrop)?;
+    airdrop::Reserve::update(deps.storage, reserved, &airdrop)?;
+
+    Ok(())
+}
+
+fn release_allocation(
+    storage: &mut dyn Storage,
+    airdrop_id: &u64,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let mut airdrop = Airdrop::load(storage, airdrop_id)?;
+
+    let reserved = airdrop.reserved();
+    airdrop.state.funded = airdrop.state.funded.saturating_sub(amount);
+    Airdrop::save(storage, airdrop_id, &airdrop)?;
+    airdrop::Reserve::update(storage, reserved, &airdrop)?;
+
+    Ok(())
+}
+
+fn deallocate_reward(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    airdrop_id: &u64,
+    recipient: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    if Airdrop::may_load(storage, airdrop_id)?.is_none() {
+        return Err(ContractError::AirdropNotFound {});
+    }
+
+    let recipient = api.addr_validate(recipient)?;
+    let mut airdrop_reward = airdrop::Reward::load(storage, &recipient, airdrop_id)?;
+    if airdrop_reward.reward < amount {
+        return Err(ContractError::InsufficientReward {});
+    }
+    airdrop_reward.reward -= amount;
+    airdrop::Reward::save(storage, &recipient, airdrop_id, &airdrop_reward)?;
+
+    // reward accrued by staking can be deallocated as well, so the allocation may run out first
+    let recipient_raw = api.addr_canonicalize(recipient.as_str())?;
+    let mut allocation = airdrop::Allocation::load(storage, airdrop_id, &recipient_raw)?;
+    allocation.amount = allocation.amount.saturating_sub(amount);
+    airdrop::Allocation::save(storage, airdrop_id, &recipient_raw, &allocation)?;
+
+    Ok(())
+}
+
 pub fn update(
     deps: DepsMut,
     env: Env,
     _info: MessageInfo,
     target: Option<String>,
 ) -> ExecuteResult {
-    let response = Response::new().add_attribute("action", "airdrop_update");
+    let mut response = Response::new().add_attribute("action", "airdrop_update");
 
-    let target = target.map(|x| deps.api.addr_validate(x.as_str()).unwrap());
-    let state = State::load(deps.storage)?;
+    let target = match target {
+        Some(target) => Some(validate_address(deps.api, target.as_str())?),
+        None => None,
+    };
+    let mut state = State::load(deps.storage)?;
 
+    let mut finished: Vec<u64> = vec![];
     for airdrop_id in state.airdrop_update_candidates.iter() {
-        let mut airdrop = match Airdrop::load(deps.storage, airdrop_id) {
+        let airdrop = update_airdrop(deps.storage, &env, &state, airdrop_id)?;
+
+        if let Some(target) = &target {
+            settle_reward(
+                deps.storage,
+                deps.api,
+                &env,
+                &state,
+                target,
+                airdrop_id,
+                &airdrop,
+            )?;
+        }
+
+        if airdrop.is_final() {
+            finished.push(*airdrop_id);
+        }
+    }
+
+    // pruned airdrops may still hold rewards of stakers who haven't been settled since,
+    // stakers without share have nothing to settle
+    if let Some(target) = &target {
+        let target_raw = deps.api.addr_canonicalize(target.as_str())?;
+        let last_settled = if TokenManager::load(deps.storage, &target_raw)?
+            .share
+            .is_zero()
+        {
+            env.block.time.seconds()
+        } else {
+            airdrop::Reward::load_last_settled(deps.storage, target)?
+        };
+        for airdrop_id in Airdrop::load_finished_after(deps.storage, last_settled)?.iter() {
+            let airdrop = match Airdrop::may_load(deps.storage, airdrop_id)? {
+                Some(airdrop) => airdrop,
+                None => continue,
+            };
+            settle_reward(
+                deps.storage,
+                deps.api,
+                &env,
+                &state,
+                target,
+                airdrop_id,
+                &airdrop,
+            )?;
+        }
+        airdrop::Reward::save_last_settled(deps.storage, target, env.block.time.seconds())?;
+    }
+
+    response = response.add_attributes(vec![(
+        "updated",
+        format!("{:?}", state.airdrop_update_candidates),
+    )]);
+
+    if !finished.is_empty() {
+        prune_candidates(deps.storage, &mut state, &finished)?;
+        response = response.add_attribute("pruned", format!("{:?}", finished));
+    }
+
+    Ok(response)
+}
+
+/// Removes finished airdrops from the update candidates without touching the others
+pub fn compact(deps: DepsMut, env: Env, _info: MessageInfo) -> ExecuteResult {
+    let response = Response::new().add_attribute("action", "airdrop_compact");
+
+    let mut state = State::load(deps.storage)?;
+
+    let mut finished: Vec<u64> = vec![];
+    for airdrop_id in state.airdrop_update_candidates.iter() {
+        let airdrop = match Airdrop::may_load(deps.storage, airdrop_id)? {
             Some(airdrop) => airdrop,
             None => return Err(ContractError::AirdropNotFound {}),
         };
-        let applicable_time = airdrop.applicable_time(&env.block);
-
-        airdrop.state.reward_per_token_stored =
-            if airdrop.finish() == airdrop.state.last_update_time {
-                airdrop.state.reward_per_token_stored // because it's already latest
-            } else {
-                airdrop.state.reward_per_token_stored
-                    + calculate_reward_per_token(
-                        &applicable_time,
-                        &state.total_share,
-                        &airdrop.config.reward_rate,
-                        &airdrop.state.last_update_time,
-                    )?
-            };
-        airdrop.state.last_update_time = applicable_time;
+        if airdrop.finish() > env.block.time.seconds() {
+            continue;
+        }
 
-        Airdrop::save(deps.storage, airdrop_id, &airdrop)?;
+        update_airdrop(deps.storage, &env, &state, airdrop_id)?;
+        finished.push(*airdrop_id);
+    }
 
-        if let Some(target) = &target {
-            let mut airdrop_reward = airdrop::Reward::load(deps.storage, target, airdrop_id)?;
-            let token_manager =
-                TokenManager::load(deps.storage, &deps.api.addr_canonicalize(target.as_str())?)?;
+    prune_candidates(deps.storage, &mut state, &finished)?;
+
+    Ok(response.add_attributes(vec![
+        ("pruned", format!("{:?}", finished)),
+        (
+            "candidates",
+            state.airdrop_update_candidates.len().to_string(),
+        ),
+    ]))
+}
 
-            airdrop_reward.reward = calculate_rewards(
+fn update_airdrop(
+    storage: &mut dyn Storage,
+    env: &Env,
+    state: &State,
+    airdrop_id: &u64,
+) -> Result<Airdrop, ContractError> {
+    let mut airdrop = match Airdrop::may_load(storage, airdrop_id)? {
+        Some(airdrop) => airdrop,
+        None => return Err(ContractError::AirdropNotFound {}),
+    };
+    let applicable_time = airdrop.applicable_time(&env.block);
+
+    airdrop.state.reward_per_token_stored = if airdrop.is_final() {
+        airdrop.state.reward_per_token_stored // because it's already latest
+    } else {
+        airdrop.state.reward_per_token_stored
+            + calculate_reward_per_token(
                 &applicable_time,
                 &state.total_share,
-                &token_manager.share,
-                &airdrop,
-                &airdrop_reward,
-            )?;
-            airdrop_reward.reward_per_token_paid = airdrop.state.reward_per_token_stored;
+                &airdrop.config.reward_rate,
+                &airdrop.state.last_update_time,
+            )?
+    };
+    airdrop.state.last_update_time = applicable_time;
 
-            airdrop::Reward::save(deps.storage, target, airdrop_id, &airdrop_reward)?;
-        }
+    Airdrop::save(storage, airdrop_id, &airdrop)?;
+
+    Ok(airdrop)
+}
+
+fn settle_reward(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    env: &Env,
+    state: &State,
+    target: &Addr,
+    airdrop_id: &u64,
+    airdrop: &Airdrop,
+) -> StdResult<()> {
+    let mut airdrop_reward = airdrop::Reward::load(storage, target, airdrop_id)?;
+    let token_manager = TokenManager::load(storage, &api.addr_canonicalize(target.as_str())?)?;
+
+    airdrop_reward.reward = calculate_rewards(
+        &airdrop.applicable_time(&env.block),
+        &state.total_share,
+        &token_manager.share,
+        airdrop,
+        &airdrop_reward,
+    )?;
+    airdrop_reward.reward_per_token_paid = airdrop.state.reward_per_token_stored;
+
+    airdrop::Reward::save(storage, target, airdrop_id, &airdrop_reward)
+}
+
+fn prune_candidates(
+    storage: &mut dyn Storage,
+    state: &mut State,
+    finished: &[u64],
+) -> Result<(), ContractError> {
+    for airdrop_id in finished.iter() {
+        let airdrop = match Airdrop::may_load(storage, airdrop_id)? {
+            Some(airdrop) => airdrop,
+            None => return Err(ContractError::AirdropNotFound {}),
+        };
+        Airdrop::index_finished(storage, airdrop_id, &airdrop)?;
     }
 
-    Ok(response.add_attributes(vec![(
-        "updated",
-        format!("{:?}", state.airdrop_update_candidates),
-    )]))
+    state
+        .airdrop_update_candidates
+        .retain(|airdrop_id| !finished.contains(airdrop_id));
+    State::save(storage, state)?;
+
+    Ok(())
 }
 
-pub fn claim(deps: DepsMut, env: Env, info: MessageInfo, sender: Option<String>) -> ExecuteResult {
-    let sender = sender
-        .map(|x| deps.api.addr_validate(x.as_str()).unwrap())
-        .unwrap_or(info.sender);
+pub fn claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    sender: Option<String>,
+    airdrop_ids: Option<Vec<u64>>,
+    recipient: Option<String>,
+) -> ExecuteResult {
+    let sender = match sender {
+        Some(sender) => validate_address(deps.api, sender.as_str())?,
+        None => info.sender.clone(),
+    };
+    // rewards of others can be claimed on their behalf, but only to themselves
+    if recipient.is_some() && sender != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let recipient = match recipient {
+        Some(recipient) => Some(validate_address(deps.api, recipient.as_str())?.to_string()),
+        None => None,
+    };
 
-    let state = State::load(deps.storage).unwrap();
+    let state = State::load(deps.storage)?;
     let token_manager =
         TokenManager::load(deps.storage, &deps.api.addr_canonicalize(sender.as_str())?)?;
 
-    let airdrop_rewards =
-        airdrop::Reward::load_range(deps.storage, &sender, None, Some(MAX_QUERY_LIMIT), None)?;
+    let airdrop_rewards = match airdrop_ids {
+        Some(mut airdrop_ids) => {
+            airdrop_ids.sort_unstable();
+            airdrop_ids.dedup();
+            airdrop_ids
+                .into_iter()
+                .map(
+                    |airdrop_id| -> Result<(u64, airdrop::Reward), ContractError> {
+                        if Airdrop::may_load(deps.storage, &airdrop_id)?.is_none() {
+                            return Err(ContractError::AirdropNotFound {});
+                        }
+                        Ok((
+                            airdrop_id,
+                            airdrop::Reward::load(deps.storage, &sender, &airdrop_id)?,
+                        ))
+                    },
+                )
+                .collect::<Result<Vec<(u64, airdrop::Reward)>, ContractError>>()?
+        }
+        None => {
+            airdrop::Reward::load_range(deps.storage, &sender, None, Some(MAX_QUERY_LIMIT), None)?
+        }
+    };
 
     let response = Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: env.contract.address.to_string(),
@@ -217,54 +736,36 @@ pub fn claim(deps: DepsMut, env: Env, info: MessageInfo, sender: Option<String>)
         funds: vec![],
     }));
 
-    Ok(response.add_messages(
-        airdrop_rewards
-            .iter()
-            .map(|(airdrop_id, airdrop_reward)| {
-                let mut airdrop = Airdrop::load(deps.storage, airdrop_id).unwrap();
-                let applicable_time = airdrop.applicable_time(&env.block);
-
-                airdrop.state.reward_per_token_stored =
-                    if airdrop.finish() == airdrop.state.last_update_time {
-                        airdrop.state.reward_per_token_stored // because it's already latest
-                    } else {
-                        airdrop.state.reward_per_token_stored
-                            + calculate_reward_per_token(
-                                &applicable_time,
-                                &state.total_share,
-                                &airdrop.config.reward_rate,
-                                &airdrop.state.last_update_time,
-                            )
-                            .unwrap()
-                    };
-                airdrop.state.last_update_time = applicable_time;
-
-                let mut airdrop_reward = airdrop_reward.clone();
-                airdrop_reward.reward = calculate_rewards(
-                    &applicable_time,
-                    &state.total_share,
-                    &token_manager.share,
-                    &airdrop,
-                    &airdrop_reward,
-                )
-                .unwrap();
-                airdrop_reward.reward_per_token_paid = airdrop.state.reward_per_token_stored;
-
-                (airdrop_id, airdrop_reward)
-            })
-            .filter(|(_, airdrop_reward)| !airdrop_reward.reward.is_zero())
-            .map(|(airdrop_id, _)| {
-                CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: env.contract.address.to_string(),
-                    msg: to_binary(&ExecuteMsg::Airdrop(AirdropMsg::ClaimInternal {
-                        sender: sender.to_string(),
-                        airdrop_id: *airdrop_id,
-                    }))
-                    .unwrap(),
-                    funds: vec![],
-                })
-            }),
-    ))
+    let now = env.block.time.seconds();
+    let mut claim_msgs: Vec<CosmosMsg> = vec![];
+    for (airdrop_id, airdrop_reward) in airdrop_rewards.iter() {
+        let airdrop = match Airdrop::may_load(deps.storage, airdrop_id)? {
+            Some(airdrop) => airdrop,
+            None => return Err(ContractError::AirdropNotFound {}),
+        };
+        let reward = calculate_pending_reward(
+            now,
+            &state.total_share,
+            &token_manager.share,
+            &airdrop,
+            airdrop_reward,
+        )?;
+        if reward.is_zero() {
+            continue;
+        }
+
+        claim_msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::Airdrop(AirdropMsg::ClaimInternal {
+                sender: sender.to_string(),
+                airdrop_id: *airdrop_id,
+                recipient: recipient.clone(),
+            }))?,
+            funds: vec![],
+        }));
+    }
+
+    Ok(response.add_messages(claim_msgs))
 }
 
 pub fn claim_internal(
@@ -273,20 +774,41 @@ pub fn claim_internal(
     info: MessageInfo,
     sender: String,
     airdrop_id: u64,
+    recipient: Option<String>,
 ) -> ExecuteResult {
+    let recipient = recipient.unwrap_or_else(|| sender.clone());
     let response = Response::new()
         .add_attribute("action", "airdrop_claim")
-        .add_attribute("target", sender.as_str());
+        .add_attribute("target", sender.as_str())
+        .add_attribute("recipient", recipient.as_str());
 
     if env.contract.address != info.sender {
         return Err(ContractError::Unauthorized {});
     }
 
-    let sender = deps.api.addr_validate(sender.as_str())?;
+    let sender = validate_address(deps.api, sender.as_str())?;
+    let recipient = validate_address(deps.api, recipient.as_str())?;
     let airdrop_reward = airdrop::Reward::load(deps.storage, &sender, &airdrop_id)?;
-    let airdrop = Airdrop::load(deps.storage, &airdrop_id).unwrap();
+    let mut airdrop = match Airdrop::may_load(deps.storage, &airdrop_id)? {
+        Some(airdrop) => airdrop,
+        None => return Err(ContractError::AirdropNotFound {}),
+    };
     let claim_amount = airdrop_reward.reward;
 
+    let reserved = airdrop.reserved();
+    airdrop.state.distributed += claim_amount;
+    Airdrop::save(deps.storage, &airdrop_id, &airdrop)?;
+    airdrop::Reserve::update(deps.storage, reserved, &airdrop)?;
+
+    let mut state = State::load(deps.storage)?;
+    release_fund(
+        deps.as_ref(),
+        &mut state,
+        &airdrop.config.reward_token,
+        reserved - airdrop.reserved(),
+    )?;
+    State::save(deps.storage, &state)?;
+
     airdrop::Reward::save(
         deps.storage,
         &sender,
@@ -298,20 +820,41 @@ pub fn claim_internal(
     )?;
 
     Ok(response
-        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: airdrop.config.reward_token.to_string(),
-            msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                recipient: sender.to_string(),
-                amount: claim_amount,
-            })?,
-            funds: vec![],
-        }))
+        .add_message(transfer_msg(
+            &airdrop.config.reward_token,
+            &recipient,
+            claim_amount,
+        )?)
         .add_attributes(vec![
-            ("token", airdrop.config.reward_token.as_str()),
+            ("token", airdrop.config.reward_token.to_string().as_str()),
             ("amount", &claim_amount.to_string()),
         ]))
 }
 
+fn transfer_msg(
+    reward_token: &AssetInfo,
+    recipient: &Addr,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    Ok(match reward_token {
+        AssetInfo::Token { contract_addr } => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }),
+        AssetInfo::NativeToken { denom } => CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin {
+                denom: denom.to_string(),
+                amount,
+            }],
+        }),
+    })
+}
+
 pub fn calculate_reward_per_token(
     timestamp: &u64,
     total_share: &Uint128,
@@ -328,6 +871,38 @@ pub fn calculate_reward_per_token(
     }
 }
 
+/// calculate_pending_reward returns the reward of the staker at the given time,
+/// as if the airdrop and the reward were updated then
+pub fn calculate_pending_reward(
+    time: u64,
+    total_share: &Uint128,
+    user_share: &Uint128,
+    airdrop: &Airdrop,
+    airdrop_reward: &airdrop::Reward,
+) -> StdResult<Uint128> {
+    let mut airdrop = airdrop.clone();
+    let applicable_time = airdrop.applicable_time_at(time);
+
+    if !airdrop.is_final() {
+        airdrop.state.reward_per_token_stored = airdrop.state.reward_per_token_stored
+            + calculate_reward_per_token(
+                &applicable_time,
+                total_share,
+                &airdrop.config.reward_rate,
+                &airdrop.state.last_update_time,
+            )?;
+        airdrop.state.last_update_time = applicable_time;
+    }
+
+    calculate_rewards(
+        &applicable_time,
+        total_share,
+        user_share,
+        &airdrop,
+        airdrop_reward,
+    )
+}
+
 pub fn calculate_rewards(
     timestamp: &u64,
     total_share: &Uint128,
@@ -1,14 +1,16 @@
 use cosmwasm_std::{
-    from_binary, to_binary, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response, Uint128,
-    WasmMsg,
+    from_binary, to_binary, Addr, Api, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response,
+    Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw20::Cw20ReceiveMsg;
 use pylon_token::gov_msg::{AirdropMsg, Cw20HookMsg, ExecuteMsg, InstantiateMsg, StakingMsg};
+use pylon_token::ownership::Ownership;
 
 use crate::constant::{CONTRACT_NAME, CONTRACT_VERSION};
 use crate::error::ContractError;
-use crate::states::config::Config;
+use crate::states::config::{Config, ConfigChange};
+use crate::states::poll::Poll;
 use crate::states::state::State;
 
 pub type ExecuteResult = Result<Response, ContractError>;
@@ -17,19 +19,25 @@ pub mod airdrop;
 pub mod poll;
 pub mod staking;
 
+pub fn validate_address(api: &dyn Api, address: &str) -> Result<Addr, ContractError> {
+    api.addr_validate(address)
+        .map_err(|_| ContractError::InvalidAddress(address.to_string()))
+}
+
 pub fn instantiate(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> ExecuteResult {
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     let response = Response::default().add_attribute("action", "instantiate");
 
     let config = Config {
         pylon_token: deps.api.addr_canonicalize(msg.voting_token.as_str())?,
         owner: deps.api.addr_canonicalize(info.sender.as_str())?,
+        pending_owner: None,
         quorum: msg.quorum,
         threshold: msg.threshold,
         voting_period: msg.voting_period,
@@ -46,6 +54,7 @@ pub fn instantiate(
         total_deposit: Uint128::zero(),
         total_airdrop_count: 0,
         airdrop_update_candidates: vec![],
+        airdrop_fund: Uint128::zero(),
     };
 
     Config::save(deps.storage, &config)?;
@@ -67,30 +76,38 @@ pub fn receive(
     }
 
     match from_binary(&cw20_msg.msg) {
-        Ok(Cw20HookMsg::Stake {}) => Ok(Response::new()
-            // 1. Update reward
-            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: env.contract.address.to_string(),
-                msg: to_binary(&ExecuteMsg::Airdrop(AirdropMsg::Update {
-                    target: Some(cw20_msg.sender.to_string()),
-                }))?,
-                funds: vec![],
-            }))
-            // 2. Execute Stake
-            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: env.contract.address.to_string(),
-                msg: to_binary(&ExecuteMsg::Staking(StakingMsg::StakeInternal {
-                    sender: cw20_msg.sender.to_string(),
-                    amount: cw20_msg.amount,
-                }))?,
-                funds: vec![],
-            }))),
+        Ok(Cw20HookMsg::Stake { staker }) => {
+            let staker = match staker {
+                Some(staker) => validate_address(deps.api, &staker)?.to_string(),
+                None => cw20_msg.sender,
+            };
+
+            Ok(Response::new()
+                // 1. Update reward
+                .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: env.contract.address.to_string(),
+                    msg: to_binary(&ExecuteMsg::Airdrop(AirdropMsg::Update {
+                        target: Some(staker.clone()),
+                    }))?,
+                    funds: vec![],
+                }))
+                // 2. Execute Stake
+                .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: env.contract.address.to_string(),
+                    msg: to_binary(&ExecuteMsg::Staking(StakingMsg::StakeInternal {
+                        sender: staker,
+                        amount: cw20_msg.amount,
+                    }))?,
+                    funds: vec![],
+                })))
+        }
         Ok(Cw20HookMsg::CreatePoll {
             title,
             category,
             description,
             link,
             execute_msgs,
+            multiple_choice,
         }) => poll::create(
             deps,
             env,
@@ -101,7 +118,9 @@ pub fn receive(
             description,
             link,
             execute_msgs,
+            multiple_choice,
         ),
+        Ok(Cw20HookMsg::FundAirdrop {}) => airdrop::fund(deps, cw20_msg.amount),
         _ => Err(ContractError::DataShouldBeGiven {}),
     }
 }
@@ -109,8 +128,8 @@ pub fn receive(
 #[allow(clippy::too_many_arguments)]
 pub fn update_config(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    owner: Option<String>,
     quorum: Option<Decimal>,
     threshold: Option<Decimal>,
     voting_period: Option<u64>,
@@ -118,18 +137,17 @@ pub fn update_config(
     proposal_deposit: Option<Uint128>,
     snapshot_period: Option<u64>,
 ) -> ExecuteResult {
-    let response = Response::new().add_attribute("action", "update_config");
+    let mut response = Response::new().add_attribute("action", "update_config");
 
     let api = deps.api;
     let mut config = Config::load(deps.storage)?;
+    let sender = api.addr_canonicalize(info.sender.as_str())?;
 
-    if config.owner != api.addr_canonicalize(info.sender.as_str())? {
+    if config.owner != sender {
         return Err(ContractError::Unauthorized {});
     }
 
-    if let Some(owner) = owner {
-        config.owner = api.addr_canonicalize(&owner)?;
-    }
+    let prev_config = config.clone();
 
     if let Some(quorum) = quorum {
         config.quorum = quorum;
@@ -155,6 +173,88 @@ pub fn update_config(
         config.snapshot_period = period;
     }
 
+    config.validate()?;
+
+    Config::save(deps.storage, &config)?;
+
+    let changes = prev_config.diff(&config);
+    if changes.is_empty() {
+        return Ok(response);
+    }
+
+    // config updates sent by the contract itself come from an executed poll
+    let poll_id = if info.sender == env.contract.address {
+        Poll::load_temp_id(deps.storage).ok()
+    } else {
+        None
+    };
+
+    let change_id = ConfigChange::next_id(deps.storage)?;
+    for change in changes.iter() {
+        response = response.add_attributes(vec![
+            (format!("old_{}", change.field), change.old_value.clone()),
+            (format!("new_{}", change.field), change.new_value.clone()),
+        ]);
+    }
+    response = response.add_attribute("config_change_id", change_id.to_string());
+    if let Some(poll_id) = poll_id {
+        response = response.add_attribute("poll_id", poll_id.to_string());
+    }
+
+    ConfigChange::save(
+        deps.storage,
+        &change_id,
+        &ConfigChange {
+            sender,
+            height: env.block.height,
+            poll_id,
+            changes,
+        },
+    )?;
+
+    Ok(response)
+}
+
+pub fn propose_new_owner(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    expires_in: Option<u64>,
+) -> ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+    let response = Ownership {
+        owner: &mut config.owner,
+        pending_owner: &mut config.pending_owner,
+    }
+    .propose(deps.api, &env, &info, owner, expires_in)?;
+
+    Config::save(deps.storage, &config)?;
+
+    Ok(response)
+}
+
+pub fn accept_ownership(deps: DepsMut, env: Env, info: MessageInfo) -> ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+    let response = Ownership {
+        owner: &mut config.owner,
+        pending_owner: &mut config.pending_owner,
+    }
+    .accept(deps.api, &env, &info)?;
+
+    Config::save(deps.storage, &config)?;
+
+    Ok(response)
+}
+
+pub fn cancel_ownership_proposal(deps: DepsMut, info: MessageInfo) -> ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+    let response = Ownership {
+        owner: &mut config.owner,
+        pending_owner: &mut config.pending_owner,
+    }
+    .cancel(deps.api, &info)?;
+
     Config::save(deps.storage, &config)?;
 
     Ok(response)
@@ -1,9 +1,9 @@
 use cosmwasm_std::{
-    attr, to_binary, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128,
-    WasmMsg,
+    attr, to_binary, Api, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    SubMsg, Uint128, WasmMsg,
 };
 use cw20::Cw20ExecuteMsg;
-use pylon_token::gov_msg::{ExecuteMsg, PollExecuteMsg, PollMsg};
+use pylon_token::gov_msg::{ExecuteMsg, PollExecuteMsg, PollMsg, PollMultipleChoiceMsg};
 use terraswap::querier::query_token_balance;
 
 use crate::constant::POLL_EXECUTE_REPLY_ID;
@@ -11,7 +11,10 @@ use crate::error::ContractError;
 use crate::executions::ExecuteResult;
 use crate::states::bank::TokenManager;
 use crate::states::config::Config;
-use crate::states::poll::{ExecuteData, Poll, PollCategory, PollStatus, VoteOption, VoterInfo};
+use crate::states::poll::{
+    ExecuteData, Poll, PollCategory, PollMultipleChoice, PollOption, PollStatus, VoteOption,
+    VoterInfo,
+};
 use crate::states::state::State;
 
 #[allow(clippy::too_many_arguments)]
@@ -26,6 +29,7 @@ pub fn create(
     description: String,
     link: Option<String>,
     execute_msgs: Option<Vec<PollExecuteMsg>>,
+    multiple_choice: Option<PollMultipleChoiceMsg>,
 ) -> ExecuteResult {
     let response = Response::new().add_attribute("action", "create_poll");
 
@@ -43,19 +47,25 @@ pub fn create(
     state.poll_count += 1;
     state.total_deposit += deposit_amount;
 
-    let mut data_list: Vec<ExecuteData> = vec![];
-    let all_execute_data = if let Some(exec_msgs) = execute_msgs {
-        for msgs in exec_msgs {
-            let execute_data = ExecuteData {
-                order: msgs.order,
-                contract: deps.api.addr_canonicalize(&msgs.contract)?,
-                msg: msgs.msg,
-            };
-            data_list.push(execute_data)
+    let all_execute_data = to_execute_data(deps.api, execute_msgs)?;
+    let multiple_choice = match multiple_choice {
+        Some(multiple_choice) => {
+            let mut options: Vec<PollOption> = vec![];
+            for option in multiple_choice.options {
+                options.push(PollOption {
+                    name: option.name,
+                    execute_data: to_execute_data(deps.api, option.execute_msgs)?,
+                    votes: Uint128::zero(),
+                });
+            }
+            Some(PollMultipleChoice {
+                mode: multiple_choice.mode.into(),
+                options,
+                voted_amount: Uint128::zero(),
+                winning_option: None,
+            })
         }
-        Some(data_list)
-    } else {
-        None
+        None => None,
     };
 
     let sender_address_raw = deps.api.addr_canonicalize(&proposer)?;
@@ -74,6 +84,7 @@ pub fn create(
         deposit_amount,
         total_balance_at_end_poll: None,
         staked_amount: None,
+        multiple_choice,
     };
     new_poll.validate()?;
 
@@ -126,7 +137,7 @@ pub fn cast_vote(
         deps.api.addr_humanize(&config.pylon_token)?,
         env.contract.address,
     )?
-    .checked_sub(state.total_deposit)?;
+    .checked_sub(state.total_deposit + state.airdrop_fund)?;
 
     if token_manager
         .share
@@ -137,10 +148,13 @@ pub fn cast_vote(
     }
 
     // update tally info
-    if VoteOption::Yes == vote {
-        poll.yes_votes += amount;
-    } else {
-        poll.no_votes += amount;
+    match (poll.multiple_choice.as_mut(), &vote) {
+        (None, VoteOption::Yes) => poll.yes_votes += amount,
+        (None, VoteOption::No) => poll.no_votes += amount,
+        (Some(multiple_choice), VoteOption::Options(options)) => {
+            multiple_choice.cast_vote(options, amount)?
+        }
+        _ => return Err(ContractError::InvalidVoteOption {}),
     }
 
     let vote_info = VoterInfo {
@@ -218,8 +232,8 @@ pub fn execute_messages(deps: DepsMut, env: Env, info: MessageInfo, poll_id: u64
     Poll::save(deps.storage, &poll_id, &poll)?;
 
     let mut messages: Vec<CosmosMsg> = vec![];
-    if let Some(all_msgs) = poll.execute_data {
-        let mut msgs = all_msgs;
+    if let Some(all_msgs) = poll.executable_data() {
+        let mut msgs = all_msgs.clone();
         msgs.sort();
         for msg in msgs {
             messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
@@ -246,7 +260,7 @@ pub fn snapshot(deps: DepsMut, env: Env, _info: MessageInfo, poll_id: u64) -> Ex
         deps.api.addr_humanize(&config.pylon_token)?,
         env.contract.address,
     )?
-    .checked_sub(state.total_deposit)?;
+    .checked_sub(state.total_deposit + state.airdrop_fund)?;
 
     let mut poll = Poll::load(deps.storage, &poll_id)?;
     if poll.status != PollStatus::InProgress {
@@ -304,64 +318,26 @@ pub fn end(deps: DepsMut, env: Env, poll_id: u64) -> ExecuteResult {
         return Err(ContractError::PollVotingPeriod {});
     }
 
-    let no = poll.no_votes.u128();
-    let yes = poll.yes_votes.u128();
-    let tallied_weight = yes + no;
-
-    let mut poll_status = PollStatus::Rejected;
-    let mut rejected_reason = "";
-    let mut passed = false;
-
     let mut messages: Vec<CosmosMsg> = vec![];
     let config = Config::load(deps.storage)?;
     let mut state = State::load(deps.storage)?;
 
-    let (quorum, staked_weight) = if state.total_share.u128() == 0 {
-        (Decimal::zero(), Uint128::zero())
-    } else if let Some(staked_amount) = poll.staked_amount {
-        (
-            Decimal::from_ratio(tallied_weight, staked_amount),
-            staked_amount,
-        )
-    } else {
-        let staked_weight = query_token_balance(
-            &deps.querier,
-            deps.api.addr_humanize(&config.pylon_token)?,
-            env.contract.address,
-        )?
-        .checked_sub(state.total_deposit)?;
-
-        (
-            Decimal::from_ratio(tallied_weight, staked_weight),
-            staked_weight,
-        )
-    };
+    let tally = tally(deps.as_ref(), &env, &config, &state, &poll)?;
+    let poll_status = tally.projected_status();
+    let rejected_reason = tally.rejected_reason();
+    let passed = tally.passed();
+    let staked_weight = tally.staked_weight;
 
-    if tallied_weight == 0 || quorum < config.quorum {
-        // Quorum: More than quorum of the total staked tokens at the end of the voting
-        // period need to have participated in the vote.
-        rejected_reason = "Quorum not reached";
-    } else {
-        if Decimal::from_ratio(yes, tallied_weight) > config.threshold {
-            //Threshold: More than 50% of the tokens that participated in the vote
-            // (after excluding “Abstain” votes) need to have voted in favor of the proposal (“Yes”).
-            poll_status = PollStatus::Passed;
-            passed = true;
-        } else {
-            rejected_reason = "Threshold not reached";
-        }
-
-        // Refunds deposit only when quorum is reached
-        if !poll.deposit_amount.is_zero() {
-            messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: deps.api.addr_humanize(&config.pylon_token)?.to_string(),
-                funds: vec![],
-                msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                    recipient: deps.api.addr_humanize(&poll.creator)?.to_string(),
-                    amount: poll.deposit_amount,
-                })?,
-            }))
-        }
+    // Refunds deposit only when quorum is reached
+    if tally.quorum_reached && !poll.deposit_amount.is_zero() {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: deps.api.addr_humanize(&config.pylon_token)?.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: deps.api.addr_humanize(&poll.creator)?.to_string(),
+                amount: poll.deposit_amount,
+            })?,
+        }))
     }
 
     // Decrease total deposit amount
@@ -373,6 +349,11 @@ pub fn end(deps: DepsMut, env: Env, poll_id: u64) -> ExecuteResult {
     Poll::index_status(deps.storage, &poll.id, &poll_status)?;
 
     // Update poll status
+    if let Some(multiple_choice) = poll.multiple_choice.as_mut() {
+        if passed {
+            multiple_choice.winning_option = tally.winning_option;
+        }
+    }
     poll.status = poll_status;
     poll.total_balance_at_end_poll = Some(staked_weight);
     Poll::save(deps.storage, &poll_id, &poll)?;
@@ -383,3 +364,150 @@ pub fn end(deps: DepsMut, env: Env, poll_id: u64) -> ExecuteResult {
         ("passed", &passed.to_string()),
     ]))
 }
+
+pub struct Tally {
+    pub signal: bool,
+    pub multiple_choice: bool,
+    pub winning_option: Option<u32>,
+    pub staked_weight: Uint128,
+    pub participation: Decimal,
+    pub yes_ratio: Decimal,
+    pub quorum_reached: bool,
+    pub threshold_reached: bool,
+}
+
+impl Tally {
+    pub fn passed(&self) -> bool {
+        self.quorum_reached && self.threshold_reached
+    }
+
+    pub fn projected_status(&self) -> PollStatus {
+        if self.passed() && self.signal {
+            // signal polls have nothing to execute, so they are done once passed
+            PollStatus::Concluded
+        } else if self.passed() {
+            PollStatus::Passed
+        } else {
+            PollStatus::Rejected
+        }
+    }
+
+    pub fn rejected_reason(&self) -> &'static str {
+        if !self.quorum_reached {
+            "Quorum not reached"
+        } else if self.multiple_choice && self.winning_option.is_none() {
+            "No winning option"
+        } else if !self.threshold_reached {
+            "Threshold not reached"
+        } else {
+            ""
+        }
+    }
+}
+
+/*
+ * Tally the votes of a poll as if it ended at the current block
+ */
+pub fn tally(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    state: &State,
+    poll: &Poll,
+) -> Result<Tally, ContractError> {
+    // for multiple-choice polls, the leading option's votes count as yes votes
+    let winning_option = poll
+        .multiple_choice
+        .as_ref()
+        .and_then(|multiple_choice| multiple_choice.leading_option());
+    let (yes, tallied_weight) = match &poll.multiple_choice {
+        Some(multiple_choice) => (
+            winning_option.map_or(0, |option| {
+                multiple_choice.options[option as usize].votes.u128()
+            }),
+            multiple_choice.voted_amount.u128(),
+        ),
+        None => (
+            poll.yes_votes.u128(),
+            poll.yes_votes.u128() + poll.no_votes.u128(),
+        ),
+    };
+
+    let staked_weight = if state.total_share.u128() == 0 {
+        Uint128::zero()
+    } else if let Some(staked_amount) = poll.staked_amount {
+        staked_amount
+    } else {
+        query_token_balance(
+            &deps.querier,
+            deps.api.addr_humanize(&config.pylon_token)?,
+            env.contract.address.clone(),
+        )?
+        .checked_sub(state.total_deposit + state.airdrop_fund)?
+    };
+
+    let participation = if staked_weight.is_zero() {
+        Decimal::zero()
+    } else {
+        Decimal::from_ratio(tallied_weight, staked_weight)
+    };
+    let yes_ratio = if tallied_weight == 0 {
+        Decimal::zero()
+    } else {
+        Decimal::from_ratio(yes, tallied_weight)
+    };
+
+    // Quorum: More than quorum of the total staked tokens at the end of the voting
+    // period need to have participated in the vote.
+    let quorum_reached = tallied_weight != 0 && participation >= config.quorum;
+
+    //Threshold: More than 50% of the tokens that participated in the vote
+    // (after excluding “Abstain” votes) need to have voted in favor of the proposal (“Yes”).
+    // For multiple-choice polls, the share of the leading option is compared in either mode.
+    let threshold_reached = quorum_reached
+        && (poll.multiple_choice.is_none() || winning_option.is_some())
+        && yes_ratio > config.threshold;
+
+    let signal = match &poll.multiple_choice {
+        Some(multiple_choice) => winning_option
+            .and_then(|option| {
+                multiple_choice.options[option as usize]
+                    .execute_data
+                    .as_ref()
+            })
+            .map(|execute_data| execute_data.is_empty())
+            .unwrap_or(true),
+        None => poll.is_signal(),
+    };
+
+    Ok(Tally {
+        signal,
+        multiple_choice: poll.multiple_choice.is_some(),
+        winning_option,
+        staked_weight,
+        participation,
+        yes_ratio,
+        quorum_reached,
+        threshold_reached,
+    })
+}
+
+fn to_execute_data(
+    api: &dyn Api,
+    execute_msgs: Option<Vec<PollExecuteMsg>>,
+) -> StdResult<Option<Vec<ExecuteData>>> {
+    match execute_msgs {
+        Some(execute_msgs) => {
+            let mut data_list: Vec<ExecuteData> = vec![];
+            for msgs in execute_msgs {
+                data_list.push(ExecuteData {
+                    order: msgs.order,
+                    contract: api.addr_canonicalize(&msgs.contract)?,
+                    msg: msgs.msg,
+                });
+            }
+            Ok(Some(data_list))
+        }
+        None => Ok(None),
+    }
+}
@@ -1,15 +1,18 @@
 use cosmwasm_std::{
-    to_binary, CanonicalAddr, CosmosMsg, DepsMut, Env, MessageInfo, Response, Storage, Uint128,
-    WasmMsg,
+    to_binary, CanonicalAddr, CosmosMsg, DepsMut, Env, MessageInfo, Response, StdResult, Storage,
+    Uint128, WasmMsg,
 };
 use cw20::Cw20ExecuteMsg;
 use terraswap::querier::query_token_balance;
 
+use crate::constant::SHARE_RATE_CHECKPOINT_INTERVAL;
 use crate::error::ContractError;
 use crate::executions::ExecuteResult;
+use crate::states::airdrop;
 use crate::states::bank::TokenManager;
 use crate::states::config::Config;
 use crate::states::poll::{Poll, PollStatus, VoterInfo};
+use crate::states::share_rate::ShareRateCheckpoint;
 use crate::states::state::State;
 
 // INTERNAL
@@ -39,9 +42,9 @@ pub fn stake_voting_tokens(
     let total_balance = query_token_balance(
         &deps.querier,
         deps.api.addr_humanize(&config.pylon_token)?,
-        env.contract.address,
+        env.contract.address.clone(),
     )?
-    .checked_sub(state.total_deposit + amount)?;
+    .checked_sub(state.total_deposit + state.airdrop_fund + amount)?;
 
     let share = if total_balance.is_zero() || state.total_share.is_zero() {
         amount
@@ -49,11 +52,26 @@ pub fn stake_voting_tokens(
         amount.multiply_ratio(state.total_share, total_balance)
     };
 
+    // airdrops pruned before the stake hold no reward of the staker
+    if token_manager.share.is_zero() {
+        airdrop::Reward::save_last_settled(
+            deps.storage,
+            &deps.api.addr_validate(sender.as_str())?,
+            env.block.time.seconds(),
+        )?;
+    }
+
     token_manager.share += share;
     state.total_share += share;
 
     State::save(deps.storage, &state)?;
     TokenManager::save(deps.storage, &sender_address_raw, &token_manager)?;
+    record_share_rate(
+        deps.storage,
+        &env,
+        total_balance + amount,
+        state.total_share,
+    )?;
 
     Ok(response.add_attributes(vec![
         ("sender", sender.as_str()),
@@ -86,13 +104,13 @@ pub fn withdraw_voting_tokens(
         let total_balance = query_token_balance(
             &deps.querier,
             deps.api.addr_humanize(&config.pylon_token)?,
-            env.contract.address,
+            env.contract.address.clone(),
         )?
-        .checked_sub(state.total_deposit)?
+        .checked_sub(state.total_deposit + state.airdrop_fund)?
         .u128();
 
         let locked_balance =
-            compute_locked_balance(deps.storage, &mut token_manager, &sender_address_raw);
+            compute_locked_balance(deps.storage, &mut token_manager, &sender_address_raw)?;
         let locked_share = locked_balance * total_share / total_balance;
         let user_share = token_manager.share.u128();
 
@@ -113,6 +131,12 @@ pub fn withdraw_voting_tokens(
 
             state.total_share = Uint128::from(total_share - withdraw_share);
             State::save(deps.storage, &state)?;
+            record_share_rate(
+                deps.storage,
+                &env,
+                Uint128::from(total_balance.saturating_sub(withdraw_amount)),
+                state.total_share,
+            )?;
 
             send_tokens(
                 deps,
@@ -127,30 +151,59 @@ pub fn withdraw_voting_tokens(
     }
 }
 
+fn record_share_rate(
+    storage: &mut dyn Storage,
+    env: &Env,
+    balance: Uint128,
+    share: Uint128,
+) -> StdResult<()> {
+    let time = env.block.time.seconds();
+    if let Some((last_time, _)) = ShareRateCheckpoint::load_last(storage)? {
+        if time < last_time + SHARE_RATE_CHECKPOINT_INTERVAL {
+            return Ok(());
+        }
+    }
+
+    ShareRateCheckpoint::save(
+        storage,
+        &time,
+        &ShareRateCheckpoint {
+            height: env.block.height,
+            balance,
+            share,
+        },
+    )
+}
+
 // removes not in-progress poll voter info & unlock tokens
 // and returns the largest locked amount in participated polls.
 fn compute_locked_balance(
     storage: &mut dyn Storage,
     token_manager: &mut TokenManager,
     voter: &CanonicalAddr,
-) -> u128 {
-    token_manager.locked_balance.retain(|(poll_id, _)| {
-        let poll = Poll::load(storage, poll_id).unwrap();
-
-        if poll.status != PollStatus::InProgress {
+) -> Result<u128, ContractError> {
+    let mut locked_balance = vec![];
+    for (poll_id, voter_info) in token_manager.locked_balance.drain(..) {
+        let poll = match Poll::may_load(storage, &poll_id)? {
+            Some(poll) => poll,
+            None => return Err(ContractError::PollNotFound {}),
+        };
+
+        if poll.status == PollStatus::InProgress {
+            locked_balance.push((poll_id, voter_info));
+        } else {
             // remove voter info from the poll
-            VoterInfo::remove(storage, poll_id, voter);
+            VoterInfo::remove(storage, &poll_id, voter);
         }
+    }
+    token_manager.locked_balance = locked_balance;
 
-        poll.status == PollStatus::InProgress
-    });
-
-    token_manager
+    Ok(token_manager
         .locked_balance
         .iter()
         .map(|(_, v)| v.balance.u128())
         .max()
-        .unwrap_or_default()
+        .unwrap_or_default())
 }
 
 fn send_tokens(
@@ -1,25 +1,82 @@
 use crate::constant::{CONTRACT_NAME, CONTRACT_VERSION};
-use cosmwasm_std::{DepsMut, Env, Order, Response};
-use cosmwasm_storage::ReadonlyBucket;
+use cosmwasm_std::{
+    from_slice, Addr, Decimal, DepsMut, Env, Order, Response, StdResult, Storage, Uint128,
+};
+use cosmwasm_storage::{ReadonlyBucket, ReadonlyPrefixedStorage};
 use cw2::set_contract_version;
+use serde::{Deserialize, Serialize};
+use terraswap::asset::AssetInfo;
 
-use crate::states::poll::Poll;
-use crate::states::PREFIX_POLL;
+use crate::states::airdrop::{self, Airdrop};
+use crate::states::poll::{Poll, PollStatus};
+use crate::states::{decode_id, PREFIX_AIRDROP, PREFIX_POLL};
+
+#[derive(Serialize, Deserialize)]
+struct LegacyAirdropConfig {
+    start: u64,
+    period: u64,
+    reward_token: Addr,
+    reward_rate: Decimal,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LegacyAirdrop {
+    config: LegacyAirdropConfig,
+    state: airdrop::State,
+}
 
 pub fn migrate(deps: DepsMut, _env: Env) -> super::MigrateResult {
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    let polls: Vec<Poll> = ReadonlyBucket::<Poll>::new(deps.storage, PREFIX_POLL)
+    let mut polls: Vec<Poll> = ReadonlyBucket::<Poll>::new(deps.storage, PREFIX_POLL)
         .range(None, None, Order::Ascending)
-        .map(|item| -> Poll {
-            let (_, v) = item.unwrap();
-            v
+        .map(|item| -> StdResult<Poll> {
+            let (_, v) = item?;
+            Ok(v)
         })
-        .collect();
+        .collect::<StdResult<Vec<Poll>>>()?;
+
+    for poll in polls.iter_mut() {
+        // passed signal polls used to stay in the passed index forever
+        if poll.status == PollStatus::Passed && poll.is_signal() {
+            Poll::deindex_status(deps.storage, &poll.id, &PollStatus::Passed);
+            poll.status = PollStatus::Concluded;
+            Poll::save(deps.storage, &poll.id, poll)?;
+        }
+
+        Poll::index_status(deps.storage, &poll.id, &poll.status)?;
+        Poll::index_category(deps.storage, &poll.id, &poll.category)?;
+    }
+
+    // airdrops used to be cw20 only, with the reward token stored as a plain address
+    // migrated airdrops are skipped, anything else fails the migration
+    let legacy_airdrops: Vec<(u64, LegacyAirdrop)> =
+        ReadonlyPrefixedStorage::new(deps.storage, PREFIX_AIRDROP)
+            .range(None, None, Order::Ascending)
+            .map(|(k, v)| -> StdResult<Option<(u64, LegacyAirdrop)>> {
+                let airdrop_id = decode_id(&k)?;
+                match from_slice::<LegacyAirdrop>(&v) {
+                    Ok(legacy) => Ok(Some((airdrop_id, legacy))),
+                    Err(_) => from_slice::<Airdrop>(&v).map(|_| None),
+                }
+            })
+            .filter_map(|item| item.transpose())
+            .collect::<StdResult<Vec<(u64, LegacyAirdrop)>>>()?;
 
-    for poll in polls.iter() {
-        Poll::index_status(deps.storage, &poll.id, &poll.status).unwrap();
-        Poll::index_category(deps.storage, &poll.id, &poll.category).unwrap();
+    for (airdrop_id, legacy) in legacy_airdrops.into_iter() {
+        let airdrop = Airdrop {
+            config: airdrop::Config {
+                start: legacy.config.start,
+                period: legacy.config.period,
+                reward_token: AssetInfo::Token {
+                    contract_addr: legacy.config.reward_token.to_string(),
+                },
+                reward_rate: legacy.config.reward_rate,
+            },
+            state: legacy.state,
+        };
+        Airdrop::save(deps.storage, &airdrop_id, &airdrop)?;
+        airdrop::Reserve::update(deps.storage, Uint128::zero(), &airdrop)?;
     }
 
     Ok(Response::default())
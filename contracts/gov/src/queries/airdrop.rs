@@ -1,18 +1,32 @@
-use cosmwasm_std::{to_binary, Deps};
+use cosmwasm_std::{to_binary, Deps, Env, StdResult};
 use pylon_token::common::OrderBy;
-use pylon_token::gov_resp::{AirdropResponse, AirdropsResponse};
+use pylon_token::gov_resp::{
+    AirdropAllocationsResponse, AirdropAllocationsResponseItem, AirdropProjectionResponse,
+    AirdropResponse, AirdropsResponse,
+};
+use std::cmp::max;
 
+use crate::error::ContractError;
+use crate::executions::airdrop::calculate_pending_reward;
+use crate::executions::validate_address;
 use crate::queries::QueryResult;
-use crate::states::airdrop::Airdrop;
+use crate::states::airdrop::{Airdrop, Allocation, Reward};
+use crate::states::bank::TokenManager;
+use crate::states::state::State;
 
 pub fn query_airdrop(deps: Deps, airdrop_id: u64) -> QueryResult {
-    let airdrop = Airdrop::load(deps.storage, &airdrop_id).unwrap();
+    let airdrop = match Airdrop::may_load(deps.storage, &airdrop_id)? {
+        Some(airdrop) => airdrop,
+        None => return Err(ContractError::AirdropNotFound {}),
+    };
 
     Ok(to_binary(&AirdropResponse {
         start: airdrop.config.start,
         period: airdrop.config.period,
-        reward_token: airdrop.config.reward_token.to_string(),
+        reward_token: airdrop.config.reward_token.clone(),
         reward_rate: airdrop.config.reward_rate,
+        funded: airdrop.state.funded,
+        distributed: airdrop.state.distributed,
     })?)
 }
 
@@ -32,8 +46,10 @@ pub fn query_airdrops(
                 AirdropResponse {
                     start: airdrop.config.start,
                     period: airdrop.config.period,
-                    reward_token: airdrop.config.reward_token.to_string(),
+                    reward_token: airdrop.config.reward_token.clone(),
                     reward_rate: airdrop.config.reward_rate,
+                    funded: airdrop.state.funded,
+                    distributed: airdrop.state.distributed,
                 },
             )
         })
@@ -43,3 +59,72 @@ pub fn query_airdrops(
         airdrops: airdrop_responses,
     })?)
 }
+
+pub fn query_airdrop_projection(
+    deps: Deps,
+    env: Env,
+    address: String,
+    airdrop_id: u64,
+    at_time: u64,
+) -> QueryResult {
+    let airdrop = match Airdrop::may_load(deps.storage, &airdrop_id)? {
+        Some(airdrop) => airdrop,
+        None => return Err(ContractError::AirdropNotFound {}),
+    };
+    let state = State::load(deps.storage)?;
+    let address = validate_address(deps.api, address.as_str())?;
+    let token_manager =
+        TokenManager::load(deps.storage, &deps.api.addr_canonicalize(address.as_str())?)?;
+    let airdrop_reward = Reward::load(deps.storage, &address, &airdrop_id)?;
+
+    let at_time = max(at_time, env.block.time.seconds());
+    let reward = calculate_pending_reward(
+        at_time,
+        &state.total_share,
+        &token_manager.share,
+        &airdrop,
+        &airdrop_reward,
+    )?;
+
+    Ok(to_binary(&AirdropProjectionResponse {
+        airdrop_id,
+        reward_token: airdrop.config.reward_token,
+        at_time,
+        share: token_manager.share,
+        total_share: state.total_share,
+        reward,
+    })?)
+}
+
+pub fn query_airdrop_allocations(
+    deps: Deps,
+    airdrop_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    order_by: Option<OrderBy>,
+) -> QueryResult {
+    if Airdrop::may_load(deps.storage, &airdrop_id)?.is_none() {
+        return Err(ContractError::AirdropNotFound {});
+    }
+
+    let start_after = match start_after {
+        Some(start_after) => Some(deps.api.addr_canonicalize(start_after.as_str())?),
+        None => None,
+    };
+    let allocations =
+        Allocation::load_range(deps.storage, &airdrop_id, start_after, limit, order_by)?;
+
+    let allocations: StdResult<Vec<AirdropAllocationsResponseItem>> = allocations
+        .iter()
+        .map(|(address, allocation)| {
+            Ok(AirdropAllocationsResponseItem {
+                recipient: deps.api.addr_humanize(address)?.to_string(),
+                amount: allocation.amount,
+            })
+        })
+        .collect();
+
+    Ok(to_binary(&AirdropAllocationsResponse {
+        allocations: allocations?,
+    })?)
+}
@@ -4,7 +4,9 @@ use pylon_token::gov_msg::{ClaimableAirdrop, VoterInfo as GovVoterInfo};
 use pylon_token::gov_resp::{StakerResponse, StakersResponse};
 use terraswap::querier::query_token_balance;
 
-use crate::executions::airdrop::{calculate_reward_per_token, calculate_rewards};
+use crate::error::ContractError;
+use crate::executions::airdrop::calculate_pending_reward;
+use crate::executions::validate_address;
 use crate::queries::QueryResult;
 use crate::states::airdrop::{Airdrop, Reward as AirdropReward};
 use crate::states::bank::TokenManager;
@@ -13,6 +15,7 @@ use crate::states::poll::{Poll, PollStatus};
 use crate::states::state::State;
 
 pub fn query_staker(deps: Deps, env: Env, address: String) -> QueryResult {
+    validate_address(deps.api, address.as_str())?;
     let config = Config::load(deps.storage)?;
     let state = State::load(deps.storage)?;
     let token_manager = TokenManager::load(deps.storage, &deps.api.addr_canonicalize(&address)?)?;
@@ -22,7 +25,7 @@ pub fn query_staker(deps: Deps, env: Env, address: String) -> QueryResult {
         deps.api.addr_humanize(&config.pylon_token)?,
         env.contract.address.clone(),
     )?
-    .checked_sub(state.total_deposit)?;
+    .checked_sub(state.total_deposit + state.airdrop_fund)?;
 
     Ok(to_binary(&to_response(
         &deps,
@@ -31,7 +34,7 @@ pub fn query_staker(deps: Deps, env: Env, address: String) -> QueryResult {
         &state.total_share,
         &total_balance,
         &token_manager,
-    ))?)
+    )?)?)
 }
 
 pub fn query_stakers(
@@ -41,39 +44,40 @@ pub fn query_stakers(
     limit: Option<u32>,
     order: Option<OrderBy>,
 ) -> QueryResult {
-    let state = State::load(deps.storage).unwrap();
-    let config = Config::load(deps.storage).unwrap();
-    let managers = TokenManager::load_range(
-        deps.storage,
-        start_after.map(|x| deps.api.addr_canonicalize(x.as_str()).unwrap()),
-        limit,
-        order,
-    )?;
+    let state = State::load(deps.storage)?;
+    let config = Config::load(deps.storage)?;
+    let start_after = match start_after {
+        Some(start_after) => Some(deps.api.addr_canonicalize(start_after.as_str())?),
+        None => None,
+    };
+    let managers = TokenManager::load_range(deps.storage, start_after, limit, order)?;
 
     let total_balance = query_token_balance(
         &deps.querier,
         deps.api.addr_humanize(&config.pylon_token)?,
         env.contract.address.clone(),
     )?
-    .checked_sub(state.total_deposit)?;
+    .checked_sub(state.total_deposit + state.airdrop_fund)?;
 
-    let stakers: Vec<(String, StakerResponse)> = managers
+    let stakers = managers
         .iter()
-        .map(|(address, token_manager)| -> (String, StakerResponse) {
-            let address = deps.api.addr_humanize(address).unwrap();
-            (
-                address.to_string(),
-                to_response(
-                    &deps,
-                    &env,
-                    address.as_str(),
-                    &state.total_share,
-                    &total_balance,
-                    token_manager,
-                ),
-            )
-        })
-        .collect();
+        .map(
+            |(address, token_manager)| -> Result<(String, StakerResponse), ContractError> {
+                let address = deps.api.addr_humanize(address)?;
+                Ok((
+                    address.to_string(),
+                    to_response(
+                        &deps,
+                        &env,
+                        address.as_str(),
+                        &state.total_share,
+                        &total_balance,
+                        token_manager,
+                    )?,
+                ))
+            },
+        )
+        .collect::<Result<Vec<(String, StakerResponse)>, ContractError>>()?;
 
     Ok(to_binary(&StakersResponse { stakers })?)
 }
@@ -85,7 +89,7 @@ fn to_response(
     total_share: &Uint128,
     total_balance: &Uint128,
     token_manager: &TokenManager,
-) -> StakerResponse {
+) -> Result<StakerResponse, ContractError> {
     let balance = if !total_share.is_zero() {
         token_manager
             .share
@@ -94,81 +98,59 @@ fn to_response(
         Uint128::zero()
     };
 
-    let locked_balance = token_manager
-        .locked_balance
-        .iter()
-        .filter(|(poll_id, _)| {
-            let poll = Poll::load(deps.storage, poll_id).unwrap();
-
-            poll.status == PollStatus::InProgress
-        })
-        .map(|(poll_id, voter_info)| -> (u64, GovVoterInfo) {
-            (
-                *poll_id,
-                GovVoterInfo {
-                    vote: voter_info.vote.clone().into(),
-                    balance: voter_info.balance,
-                },
-            )
-        })
-        .collect();
-
-    let claimable_airdrop = AirdropReward::load_range(
-        deps.storage,
-        &deps.api.addr_validate(staker).unwrap(),
-        None,
-        None,
-        None,
-    )
-    .unwrap();
-
-    let claimable_airdrop = claimable_airdrop
-        .iter()
-        .map(|(airdrop_id, airdrop_reward)| {
-            let mut airdrop = Airdrop::load(deps.storage, airdrop_id).unwrap();
-            let applicable_time = airdrop.applicable_time(&env.block);
-
-            airdrop.state.reward_per_token_stored =
-                if airdrop.finish() == airdrop.state.last_update_time {
-                    airdrop.state.reward_per_token_stored // because it's already latest
-                } else {
-                    airdrop.state.reward_per_token_stored
-                        + calculate_reward_per_token(
-                            &applicable_time,
-                            total_share,
-                            &airdrop.config.reward_rate,
-                            &airdrop.state.last_update_time,
-                        )
-                        .unwrap()
-                };
-            airdrop.state.last_update_time = applicable_time;
-
-            let mut airdrop_reward = airdrop_reward.clone();
-            airdrop_reward.reward = calculate_rewards(
-                &applicable_time,
-                total_share,
-                &token_manager.share,
-                &airdrop,
-                &airdrop_reward,
-            )
-            .unwrap();
-            airdrop_reward.reward_per_token_paid = airdrop.state.reward_per_token_stored;
+    let mut locked_balance: Vec<(u64, GovVoterInfo)> = vec![];
+    for (poll_id, voter_info) in token_manager.locked_balance.iter() {
+        let poll = match Poll::may_load(deps.storage, poll_id)? {
+            Some(poll) => poll,
+            None => return Err(ContractError::PollNotFound {}),
+        };
+        if poll.status != PollStatus::InProgress {
+            continue;
+        }
+
+        locked_balance.push((
+            *poll_id,
+            GovVoterInfo {
+                vote: voter_info.vote.clone().into(),
+                balance: voter_info.balance,
+            },
+        ));
+    }
 
-            (
-                *airdrop_id,
-                ClaimableAirdrop {
-                    token: airdrop.config.reward_token.to_string(),
-                    amount: airdrop_reward.reward,
-                },
-            )
-        })
-        .filter(|(_, airdrop_reward)| !airdrop_reward.amount.is_zero())
-        .collect();
+    let staker = validate_address(deps.api, staker)?;
+    let airdrop_rewards = AirdropReward::load_range(deps.storage, &staker, None, None, None)?;
+
+    let now = env.block.time.seconds();
+    let mut claimable_airdrop: Vec<(u64, ClaimableAirdrop)> = vec![];
+    for (airdrop_id, airdrop_reward) in airdrop_rewards.iter() {
+        let airdrop = match Airdrop::may_load(deps.storage, airdrop_id)? {
+            Some(airdrop) => airdrop,
+            None => return Err(ContractError::AirdropNotFound {}),
+        };
+        let amount = calculate_pending_reward(
+            now,
+            total_share,
+            &token_manager.share,
+            &airdrop,
+            airdrop_reward,
+        )?;
+        if amount.is_zero() {
+            continue;
+        }
+
+        claimable_airdrop.push((
+            *airdrop_id,
+            ClaimableAirdrop {
+                token: airdrop.config.reward_token.to_string(),
+                amount,
+            },
+        ));
+    }
 
-    StakerResponse {
+    Ok(StakerResponse {
         balance,
         share: token_manager.share,
         locked_balance,
         claimable_airdrop,
-    }
+    })
 }
@@ -1,13 +1,23 @@
 use cosmwasm_std::{to_binary, Deps};
-use pylon_token::gov_resp::ConfigResponse;
+use pylon_token::common::{OrderBy, PendingOwnerResponse};
+use pylon_token::gov_resp::{
+    ConfigChangeResponse, ConfigFieldChangeResponse, ConfigHistoryResponse, ConfigResponse,
+};
 
 use crate::queries::QueryResult;
-use crate::states::config::Config;
+use crate::states::config::{Config, ConfigChange};
 
 pub fn query_config(deps: Deps) -> QueryResult {
     let config = Config::load(deps.storage)?;
     Ok(to_binary(&ConfigResponse {
         owner: deps.api.addr_humanize(&config.owner)?.to_string(),
+        pending_owner: match config.pending_owner {
+            Some(pending_owner) => Some(PendingOwnerResponse {
+                owner: deps.api.addr_humanize(&pending_owner.owner)?.to_string(),
+                expires_at: pending_owner.expires_at,
+            }),
+            None => None,
+        },
         pylon_token: deps.api.addr_humanize(&config.pylon_token)?.to_string(),
         quorum: config.quorum,
         threshold: config.threshold,
@@ -17,3 +27,33 @@ pub fn query_config(deps: Deps) -> QueryResult {
         snapshot_period: config.snapshot_period,
     })?)
 }
+
+pub fn query_config_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    order_by: Option<OrderBy>,
+) -> QueryResult {
+    let changes = ConfigChange::load_range(deps.storage, start_after, limit, order_by)?;
+
+    let mut history: Vec<ConfigChangeResponse> = vec![];
+    for (id, change) in changes.into_iter() {
+        history.push(ConfigChangeResponse {
+            id,
+            sender: deps.api.addr_humanize(&change.sender)?.to_string(),
+            height: change.height,
+            poll_id: change.poll_id,
+            changes: change
+                .changes
+                .into_iter()
+                .map(|c| ConfigFieldChangeResponse {
+                    field: c.field,
+                    old_value: c.old_value,
+                    new_value: c.new_value,
+                })
+                .collect(),
+        });
+    }
+
+    Ok(to_binary(&ConfigHistoryResponse { history })?)
+}
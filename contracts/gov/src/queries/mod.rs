@@ -14,6 +14,6 @@ pub type QueryResult = Result<Binary, ContractError>;
 
 pub fn query_api_version(deps: Deps) -> QueryResult {
     Ok(to_binary(&APIVersionResponse {
-        version: get_contract_version(deps.storage).unwrap().version,
+        version: get_contract_version(deps.storage)?.version,
     })?)
 }
@@ -1,22 +1,72 @@
-use cosmwasm_std::{to_binary, Deps, StdResult};
+use cosmwasm_std::{to_binary, Deps, Env, StdResult};
 use pylon_token::common::OrderBy;
 use pylon_token::gov_msg::PollExecuteMsg;
-use pylon_token::gov_resp::{PollResponse, PollsResponse, VotersResponse, VotersResponseItem};
+use pylon_token::gov_resp::{
+    PollMultipleChoiceResponse, PollOptionResponse, PollResponse, PollTallyResponse, PollsResponse,
+    VotersResponse, VotersResponseItem,
+};
 
 use crate::error::ContractError;
+use crate::executions::poll::tally;
 use crate::queries::QueryResult;
-use crate::states::poll::{Poll, PollCategory, PollStatus, VoterInfo};
+use crate::states::config::Config;
+use crate::states::poll::{ExecuteData, Poll, PollCategory, PollStatus, VoterInfo};
+use crate::states::state::State;
 
 pub fn query_poll(deps: Deps, poll_id: u64) -> QueryResult {
     let poll = match Poll::may_load(deps.storage, &poll_id)? {
-        Some(poll) => Some(poll),
+        Some(poll) => poll,
         None => return Err(ContractError::PollNotFound {}),
-    }
-    .unwrap();
+    };
 
     Ok(to_binary(&to_response(deps, &poll)?)?)
 }
 
+pub fn query_poll_tally(deps: Deps, env: Env, poll_id: u64) -> QueryResult {
+    let mut poll = match Poll::may_load(deps.storage, &poll_id)? {
+        Some(poll) => poll,
+        None => return Err(ContractError::PollNotFound {}),
+    };
+    let config = Config::load(deps.storage)?;
+    let state = State::load(deps.storage)?;
+
+    // ended polls are tallied against the balance recorded at the end
+    if poll.status != PollStatus::InProgress {
+        poll.staked_amount = poll.total_balance_at_end_poll.or(poll.staked_amount);
+    }
+
+    let tally = tally(deps, &env, &config, &state, &poll)?;
+    let projected_status = if poll.status == PollStatus::InProgress {
+        tally.projected_status()
+    } else {
+        poll.status.clone()
+    };
+
+    let height = env.block.height;
+    let timelock_end_height = poll.end_height + config.timelock_period;
+    let blocks_until_timelock_end = timelock_end_height.saturating_sub(height);
+
+    Ok(to_binary(&PollTallyResponse {
+        poll_id,
+        status: poll.status.into(),
+        staked_weight: tally.staked_weight,
+        participation: tally.participation,
+        yes_ratio: tally.yes_ratio,
+        quorum: config.quorum,
+        threshold: config.threshold,
+        quorum_reached: tally.quorum_reached,
+        threshold_reached: tally.threshold_reached,
+        winning_option: tally.winning_option,
+        projected_status: projected_status.clone().into(),
+        blocks_until_end: poll.end_height.saturating_sub(height),
+        blocks_until_timelock_end,
+        blocks_until_executable: match projected_status {
+            PollStatus::Passed => Some(blocks_until_timelock_end),
+            _ => None,
+        },
+    })?)
+}
+
 pub fn query_polls(
     deps: Deps,
     start_after: Option<u64>,
@@ -25,10 +75,10 @@ pub fn query_polls(
 ) -> QueryResult {
     let polls = Poll::load_range(deps.storage, start_after, limit, order_by)?;
 
-    let poll_responses: Vec<PollResponse> = polls
+    let poll_responses = polls
         .iter()
-        .map(|poll| to_response(deps, poll).unwrap())
-        .collect();
+        .map(|poll| to_response(deps, poll))
+        .collect::<StdResult<Vec<PollResponse>>>()?;
 
     Ok(to_binary(&PollsResponse {
         polls: poll_responses,
@@ -50,10 +100,10 @@ pub fn query_polls_with_status_filter(
         order_by,
     )?;
 
-    let poll_responses: Vec<PollResponse> = polls
+    let poll_responses = polls
         .iter()
-        .map(|poll| to_response(deps, poll).unwrap())
-        .collect();
+        .map(|poll| to_response(deps, poll))
+        .collect::<StdResult<Vec<PollResponse>>>()?;
 
     Ok(to_binary(&PollsResponse {
         polls: poll_responses,
@@ -75,10 +125,10 @@ pub fn query_polls_with_category_filter(
         order_by,
     )?;
 
-    let poll_responses: Vec<PollResponse> = polls
+    let poll_responses = polls
         .iter()
-        .map(|poll| to_response(deps, poll).unwrap())
-        .collect();
+        .map(|poll| to_response(deps, poll))
+        .collect::<StdResult<Vec<PollResponse>>>()?;
 
     Ok(to_binary(&PollsResponse {
         polls: poll_responses,
@@ -93,10 +143,9 @@ pub fn query_voters(
     order_by: Option<OrderBy>,
 ) -> QueryResult {
     let poll = match Poll::may_load(deps.storage, &poll_id)? {
-        Some(poll) => Some(poll),
+        Some(poll) => poll,
         None => return Err(ContractError::PollNotFound {}),
-    }
-    .unwrap();
+    };
 
     let voters = if poll.status != PollStatus::InProgress {
         vec![]
@@ -139,24 +188,50 @@ fn to_response(deps: Deps, poll: &Poll) -> StdResult<PollResponse> {
         description: poll.description.to_string(),
         link: poll.link.clone(),
         deposit_amount: poll.deposit_amount,
-        execute_data: if let Some(exe_msgs) = poll.execute_data.clone() {
-            let mut data_list: Vec<PollExecuteMsg> = vec![];
-
-            for msg in exe_msgs {
-                let execute_data = PollExecuteMsg {
-                    order: msg.order,
-                    contract: deps.api.addr_humanize(&msg.contract)?.to_string(),
-                    msg: msg.msg,
-                };
-                data_list.push(execute_data)
-            }
-            Some(data_list)
-        } else {
-            None
-        },
+        execute_data: to_execute_msgs(deps, &poll.execute_data)?,
         yes_votes: poll.yes_votes,
         no_votes: poll.no_votes,
         staked_amount: poll.staked_amount,
         total_balance_at_end_poll: poll.total_balance_at_end_poll,
+        multiple_choice: match &poll.multiple_choice {
+            Some(multiple_choice) => {
+                let mut options: Vec<PollOptionResponse> = vec![];
+                for option in multiple_choice.options.iter() {
+                    options.push(PollOptionResponse {
+                        name: option.name.clone(),
+                        execute_data: to_execute_msgs(deps, &option.execute_data)?,
+                        votes: option.votes,
+                    });
+                }
+                Some(PollMultipleChoiceResponse {
+                    mode: multiple_choice.mode.clone().into(),
+                    options,
+                    voted_amount: multiple_choice.voted_amount,
+                    winning_option: multiple_choice.winning_option,
+                })
+            }
+            None => None,
+        },
     })
 }
+
+fn to_execute_msgs(
+    deps: Deps,
+    execute_data: &Option<Vec<ExecuteData>>,
+) -> StdResult<Option<Vec<PollExecuteMsg>>> {
+    if let Some(exe_msgs) = execute_data {
+        let mut data_list: Vec<PollExecuteMsg> = vec![];
+
+        for msg in exe_msgs {
+            let execute_data = PollExecuteMsg {
+                order: msg.order,
+                contract: deps.api.addr_humanize(&msg.contract)?.to_string(),
+                msg: msg.msg.clone(),
+            };
+            data_list.push(execute_data)
+        }
+        Ok(Some(data_list))
+    } else {
+        Ok(None)
+    }
+}
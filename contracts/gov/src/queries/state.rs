@@ -1,17 +1,54 @@
-use cosmwasm_std::{to_binary, Deps};
-use pylon_token::gov_resp::StateResponse;
+use cosmwasm_std::{to_binary, Deps, Env};
+use pylon_token::common::OrderBy;
+use pylon_token::gov_resp::{ShareRateCheckpointResponse, ShareRateHistoryResponse, StateResponse};
+use terraswap::querier::query_token_balance;
 
 use crate::queries::QueryResult;
+use crate::states::config::Config;
+use crate::states::share_rate::{balance_per_share, ShareRateCheckpoint};
 use crate::states::state::State;
 
-pub fn query_state(deps: Deps) -> QueryResult {
+pub fn query_state(deps: Deps, env: Env) -> QueryResult {
+    let config = Config::load(deps.storage)?;
     let state = State::load(deps.storage)?;
 
+    let total_balance = query_token_balance(
+        &deps.querier,
+        deps.api.addr_humanize(&config.pylon_token)?,
+        env.contract.address,
+    )?
+    .checked_sub(state.total_deposit + state.airdrop_fund)?;
+
     Ok(to_binary(&StateResponse {
         poll_count: state.poll_count,
         total_share: state.total_share,
         total_deposit: state.total_deposit,
         total_airdrop_count: state.total_airdrop_count,
+        airdrop_update_candidate_count: state.airdrop_update_candidates.len() as u64,
         airdrop_update_candidates: state.airdrop_update_candidates,
+        total_balance,
+        balance_per_share: balance_per_share(total_balance, state.total_share),
+    })?)
+}
+
+pub fn query_share_rate_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    order_by: Option<OrderBy>,
+) -> QueryResult {
+    let checkpoints = ShareRateCheckpoint::load_range(deps.storage, start_after, limit, order_by)?;
+
+    Ok(to_binary(&ShareRateHistoryResponse {
+        checkpoints: checkpoints
+            .iter()
+            .map(|(time, checkpoint)| ShareRateCheckpointResponse {
+                time: *time,
+                height: checkpoint.height,
+                balance: checkpoint.balance,
+                share: checkpoint.share,
+                balance_per_share: checkpoint.balance_per_share(),
+            })
+            .collect(),
     })?)
 }
@@ -1,11 +1,13 @@
-use cosmwasm_std::{Addr, BlockInfo, Decimal, StdResult, Storage, Uint128};
+use cosmwasm_std::{Addr, BlockInfo, CanonicalAddr, Decimal, Order, StdResult, Storage, Uint128};
 use cosmwasm_storage::{Bucket, ReadonlyBucket};
 use pylon_token::common::OrderBy;
-use pylon_utils::range::{calc_range_end, calc_range_start};
+use pylon_utils::range::{
+    calc_range_end, calc_range_end_addr, calc_range_start, calc_range_start_addr,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::cmp::{max, min};
-use std::convert::TryInto;
+use terraswap::asset::AssetInfo;
 
 use crate::constant::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
 
@@ -13,7 +15,7 @@ use crate::constant::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
 pub struct Config {
     pub start: u64,
     pub period: u64,
-    pub reward_token: Addr,
+    pub reward_token: AssetInfo,
     pub reward_rate: Decimal,
 }
 
@@ -21,6 +23,11 @@ pub struct Config {
 pub struct State {
     pub last_update_time: u64,
     pub reward_per_token_stored: Decimal,
+    /// airdrops created before funding was tracked report zero
+    #[serde(default)]
+    pub funded: Uint128,
+    #[serde(default)]
+    pub distributed: Uint128,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -35,12 +42,16 @@ impl Default for Airdrop {
             config: Config {
                 start: 0,
                 period: 0,
-                reward_token: Addr::unchecked(""),
+                reward_token: AssetInfo::Token {
+                    contract_addr: "".to_string(),
+                },
                 reward_rate: Default::default(),
             },
             state: State {
                 last_update_time: 0,
                 reward_per_token_stored: Default::default(),
+                funded: Uint128::zero(),
+                distributed: Uint128::zero(),
             },
         }
     }
@@ -52,13 +63,29 @@ impl Airdrop {
     }
 
     pub fn applicable_time(&self, block: &BlockInfo) -> u64 {
-        min(self.finish(), max(self.config.start, block.time.seconds()))
+        self.applicable_time_at(block.time.seconds())
     }
 
-    pub fn load(storage: &dyn Storage, id: &u64) -> Option<Airdrop> {
-        ReadonlyBucket::new(storage, super::PREFIX_AIRDROP)
-            .may_load(&id.to_be_bytes())
-            .unwrap()
+    pub fn applicable_time_at(&self, time: u64) -> u64 {
+        min(self.finish(), max(self.config.start, time))
+    }
+
+    /// reserved returns the reward which is funded but not distributed yet
+    pub fn reserved(&self) -> Uint128 {
+        self.state.funded.saturating_sub(self.state.distributed)
+    }
+
+    /// is_final returns true if reward_per_token_stored won't change anymore
+    pub fn is_final(&self) -> bool {
+        self.state.last_update_time == self.finish()
+    }
+
+    pub fn load(storage: &dyn Storage, id: &u64) -> StdResult<Airdrop> {
+        ReadonlyBucket::new(storage, super::PREFIX_AIRDROP).load(&id.to_be_bytes())
+    }
+
+    pub fn may_load(storage: &dyn Storage, id: &u64) -> StdResult<Option<Airdrop>> {
+        ReadonlyBucket::new(storage, super::PREFIX_AIRDROP).may_load(&id.to_be_bytes())
     }
 
     pub fn load_range(
@@ -78,8 +105,8 @@ impl Airdrop {
             .take(limit)
             .map(
                 |item: StdResult<(Vec<u8>, Airdrop)>| -> StdResult<(u64, Airdrop)> {
-                    let (k, v) = item.unwrap();
-                    Ok((u64::from_be_bytes(k.try_into().unwrap()), v))
+                    let (k, v) = item?;
+                    Ok((super::decode_id(&k)?, v))
                 },
             )
             .collect()
@@ -88,6 +115,58 @@ impl Airdrop {
     pub fn save(storage: &mut dyn Storage, id: &u64, airdrop: &Airdrop) -> StdResult<()> {
         Bucket::new(storage, super::PREFIX_AIRDROP).save(&id.to_be_bytes(), airdrop)
     }
+
+    /// load_finished_after returns ids of the pruned airdrops which finished after the given time
+    pub fn load_finished_after(storage: &dyn Storage, time: u64) -> StdResult<Vec<u64>> {
+        let start = (time + 1).to_be_bytes();
+
+        ReadonlyBucket::new(storage, super::PREFIX_AIRDROP_FINISHED)
+            .range(Some(&start), None, Order::Ascending)
+            .map(|item: StdResult<(Vec<u8>, bool)>| -> StdResult<u64> {
+                let (k, _) = item?;
+                super::decode_id(k.get(8..).unwrap_or_default())
+            })
+            .collect()
+    }
+
+    /// index_finished marks the airdrop as pruned from the update candidates
+    pub fn index_finished(storage: &mut dyn Storage, id: &u64, airdrop: &Airdrop) -> StdResult<()> {
+        Bucket::new(storage, super::PREFIX_AIRDROP_FINISHED).save(
+            &[airdrop.finish().to_be_bytes(), id.to_be_bytes()].concat(),
+            &true,
+        )
+    }
+}
+
+/// Reserve is the running total of Airdrop::reserved over the airdrops of a reward token
+pub struct Reserve {}
+
+impl Reserve {
+    pub fn load(storage: &dyn Storage, reward_token: &AssetInfo) -> StdResult<Uint128> {
+        Ok(ReadonlyBucket::new(storage, super::PREFIX_AIRDROP_RESERVE)
+            .may_load(reward_token.to_string().as_bytes())?
+            .unwrap_or_default())
+    }
+
+    pub fn save(
+        storage: &mut dyn Storage,
+        reward_token: &AssetInfo,
+        reserved: &Uint128,
+    ) -> StdResult<()> {
+        Bucket::new(storage, super::PREFIX_AIRDROP_RESERVE)
+            .save(reward_token.to_string().as_bytes(), reserved)
+    }
+
+    /// update applies the change of the reserved reward of an airdrop to the total
+    pub fn update(storage: &mut dyn Storage, prev: Uint128, airdrop: &Airdrop) -> StdResult<()> {
+        let reward_token = &airdrop.config.reward_token;
+        let reserved = Reserve::load(storage, reward_token)?;
+        Reserve::save(
+            storage,
+            reward_token,
+            &(reserved + airdrop.reserved()).saturating_sub(prev),
+        )
+    }
 }
 
 #[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -126,8 +205,8 @@ impl Reward {
             .take(limit)
             .map(
                 |item: StdResult<(Vec<u8>, Reward)>| -> StdResult<(u64, Reward)> {
-                    let (k, v) = item.unwrap();
-                    Ok((u64::from_be_bytes(k.try_into().unwrap()), v))
+                    let (k, v) = item?;
+                    Ok((super::decode_id(&k)?, v))
                 },
             )
             .collect()
@@ -144,9 +223,91 @@ impl Reward {
         bucket.save(&airdrop_id.to_be_bytes(), reward)
     }
 
+    /// load_last_settled returns when the rewards of the address were settled lastly
+    pub fn load_last_settled(storage: &dyn Storage, address: &Addr) -> StdResult<u64> {
+        Ok(
+            ReadonlyBucket::new(storage, super::PREFIX_AIRDROP_REWARD_SETTLED)
+                .may_load(address.as_bytes())?
+                .unwrap_or_default(),
+        )
+    }
+
+    pub fn save_last_settled(
+        storage: &mut dyn Storage,
+        address: &Addr,
+        time: u64,
+    ) -> StdResult<()> {
+        Bucket::new(storage, super::PREFIX_AIRDROP_REWARD_SETTLED).save(address.as_bytes(), &time)
+    }
+
     pub fn remove(storage: &mut dyn Storage, address: &Addr, airdrop_id: &u64) {
         let mut bucket: Bucket<Reward> =
             Bucket::multilevel(storage, &[super::PREFIX_AIRDROP_REWARD, address.as_bytes()]);
         bucket.remove(&airdrop_id.to_be_bytes())
     }
 }
+
+/// Allocation tracks the amount manually allocated to a recipient, net of deallocations
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Allocation {
+    pub amount: Uint128,
+}
+
+impl Allocation {
+    pub fn load(
+        storage: &dyn Storage,
+        airdrop_id: &u64,
+        address: &CanonicalAddr,
+    ) -> StdResult<Allocation> {
+        Ok(ReadonlyBucket::multilevel(
+            storage,
+            &[super::PREFIX_AIRDROP_ALLOCATION, &airdrop_id.to_be_bytes()],
+        )
+        .may_load(address.as_slice())?
+        .unwrap_or_default())
+    }
+
+    pub fn load_range(
+        storage: &dyn Storage,
+        airdrop_id: &u64,
+        start_after: Option<CanonicalAddr>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    ) -> StdResult<Vec<(CanonicalAddr, Allocation)>> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        let (start, end, order_by) = match order_by {
+            Some(OrderBy::Asc) => (calc_range_start_addr(start_after), None, OrderBy::Asc),
+            _ => (None, calc_range_end_addr(start_after), OrderBy::Desc),
+        };
+
+        ReadonlyBucket::multilevel(
+            storage,
+            &[super::PREFIX_AIRDROP_ALLOCATION, &airdrop_id.to_be_bytes()],
+        )
+        .range(start.as_deref(), end.as_deref(), order_by.into())
+        .take(limit)
+        .map(|item| {
+            let (k, v) = item?;
+            Ok((CanonicalAddr::from(k), v))
+        })
+        .collect()
+    }
+
+    pub fn save(
+        storage: &mut dyn Storage,
+        airdrop_id: &u64,
+        address: &CanonicalAddr,
+        allocation: &Allocation,
+    ) -> StdResult<()> {
+        let mut bucket: Bucket<Allocation> = Bucket::multilevel(
+            storage,
+            &[super::PREFIX_AIRDROP_ALLOCATION, &airdrop_id.to_be_bytes()],
+        );
+        if allocation.amount.is_zero() {
+            bucket.remove(address.as_slice());
+            Ok(())
+        } else {
+            bucket.save(address.as_slice(), allocation)
+        }
+    }
+}
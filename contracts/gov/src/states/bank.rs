@@ -38,7 +38,7 @@ impl TokenManager {
             .take(limit)
             .map(
                 |elem: StdResult<(Vec<u8>, TokenManager)>| -> StdResult<(CanonicalAddr, TokenManager)> {
-                    let (k, v) = elem.unwrap();
+                    let (k, v) = elem?;
                     Ok((CanonicalAddr::from(k), v))
                 },
             )
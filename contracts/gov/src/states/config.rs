@@ -1,11 +1,18 @@
-use cosmwasm_std::{CanonicalAddr, Decimal, StdError, StdResult, Storage, Uint128};
-use cosmwasm_storage::{ReadonlySingleton, Singleton};
+use cosmwasm_std::{CanonicalAddr, Decimal, Order, StdResult, Storage, Uint128};
+use cosmwasm_storage::{Bucket, ReadonlyBucket, ReadonlySingleton, Singleton};
+use pylon_token::common::OrderBy;
+use pylon_token::ownership::PendingOwner;
+use pylon_utils::range::{calc_range_end, calc_range_start};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::constant::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
+use crate::error::ContractError;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
     pub owner: CanonicalAddr,
+    pub pending_owner: Option<PendingOwner>,
     pub pylon_token: CanonicalAddr,
     pub quorum: Decimal,
     pub threshold: Decimal,
@@ -25,17 +32,65 @@ impl Config {
         Singleton::new(storage, super::KEY_CONFIG).save(config)
     }
 
-    pub fn validate(&self) -> StdResult<()> {
+    /// diff returns the list of governance parameters that differ between two configs
+    pub fn diff(&self, other: &Config) -> Vec<ConfigFieldChange> {
+        let fields = vec![
+            ("quorum", self.quorum.to_string(), other.quorum.to_string()),
+            (
+                "threshold",
+                self.threshold.to_string(),
+                other.threshold.to_string(),
+            ),
+            (
+                "voting_period",
+                self.voting_period.to_string(),
+                other.voting_period.to_string(),
+            ),
+            (
+                "timelock_period",
+                self.timelock_period.to_string(),
+                other.timelock_period.to_string(),
+            ),
+            (
+                "proposal_deposit",
+                self.proposal_deposit.to_string(),
+                other.proposal_deposit.to_string(),
+            ),
+            (
+                "snapshot_period",
+                self.snapshot_period.to_string(),
+                other.snapshot_period.to_string(),
+            ),
+        ];
+
+        fields
+            .into_iter()
+            .filter(|(_, old_value, new_value)| old_value != new_value)
+            .map(|(field, old_value, new_value)| ConfigFieldChange {
+                field: field.to_string(),
+                old_value,
+                new_value,
+            })
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), ContractError> {
         Config::validate_quorum(self.quorum)?;
         Config::validate_threshold(self.threshold)?;
+        Config::validate_periods(
+            self.voting_period,
+            self.timelock_period,
+            self.snapshot_period,
+        )?;
+        Config::validate_proposal_deposit(self.proposal_deposit)?;
         Ok(())
     }
 
     /// validate_quorum returns an error if the quorum is invalid
     /// (we require 0-1)
-    pub fn validate_quorum(quorum: Decimal) -> StdResult<()> {
+    pub fn validate_quorum(quorum: Decimal) -> Result<(), ContractError> {
         if quorum > Decimal::one() {
-            Err(StdError::generic_err("quorum must be 0 to 1"))
+            Err(ContractError::InvalidQuorum {})
         } else {
             Ok(())
         }
@@ -43,11 +98,100 @@ impl Config {
 
     /// validate_threshold returns an error if the threshold is invalid
     /// (we require 0-1)
-    pub fn validate_threshold(threshold: Decimal) -> StdResult<()> {
+    pub fn validate_threshold(threshold: Decimal) -> Result<(), ContractError> {
         if threshold > Decimal::one() {
-            Err(StdError::generic_err("threshold must be 0 to 1"))
+            Err(ContractError::InvalidThreshold {})
         } else {
             Ok(())
         }
     }
+
+    /// validate_periods returns an error if the periods are invalid
+    /// (we require non-zero voting & timelock period, and snapshot period shorter than voting period)
+    pub fn validate_periods(
+        voting_period: u64,
+        timelock_period: u64,
+        snapshot_period: u64,
+    ) -> Result<(), ContractError> {
+        if voting_period == 0 {
+            Err(ContractError::InvalidVotingPeriod {})
+        } else if timelock_period == 0 {
+            Err(ContractError::InvalidTimelockPeriod {})
+        } else if snapshot_period >= voting_period {
+            Err(ContractError::InvalidSnapshotPeriod {})
+        } else {
+            Ok(())
+        }
+    }
+
+    /// validate_proposal_deposit returns an error if the proposal deposit is invalid
+    /// (we require non-zero deposit)
+    pub fn validate_proposal_deposit(proposal_deposit: Uint128) -> Result<(), ContractError> {
+        if proposal_deposit.is_zero() {
+            Err(ContractError::InvalidProposalDeposit {})
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigFieldChange {
+    pub field: String,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigChange {
+    pub sender: CanonicalAddr,
+    pub height: u64,
+    pub poll_id: Option<u64>,
+    pub changes: Vec<ConfigFieldChange>,
+}
+
+impl ConfigChange {
+    /// next_id returns the id following the latest recorded change (history is append-only)
+    pub fn next_id(storage: &dyn Storage) -> StdResult<u64> {
+        let last: Option<StdResult<(Vec<u8>, ConfigChange)>> =
+            ReadonlyBucket::new(storage, super::PREFIX_CONFIG_HISTORY)
+                .range(None, None, Order::Descending)
+                .next();
+
+        match last {
+            Some(item) => {
+                let (k, _) = item?;
+                Ok(super::decode_id(&k)? + 1)
+            }
+            None => Ok(0),
+        }
+    }
+
+    pub fn load_range(
+        storage: &dyn Storage,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    ) -> StdResult<Vec<(u64, ConfigChange)>> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        let (start, end, order_by) = match order_by {
+            Some(OrderBy::Asc) => (calc_range_start(start_after), None, OrderBy::Asc),
+            _ => (None, calc_range_end(start_after), OrderBy::Desc),
+        };
+
+        ReadonlyBucket::new(storage, super::PREFIX_CONFIG_HISTORY)
+            .range(start.as_deref(), end.as_deref(), order_by.into())
+            .take(limit)
+            .map(
+                |item: StdResult<(Vec<u8>, ConfigChange)>| -> StdResult<(u64, ConfigChange)> {
+                    let (k, v) = item?;
+                    Ok((super::decode_id(&k)?, v))
+                },
+            )
+            .collect()
+    }
+
+    pub fn save(storage: &mut dyn Storage, id: &u64, change: &ConfigChange) -> StdResult<()> {
+        Bucket::new(storage, super::PREFIX_CONFIG_HISTORY).save(&id.to_be_bytes(), change)
+    }
 }
@@ -1,7 +1,11 @@
+use cosmwasm_std::{StdError, StdResult};
+use std::convert::TryInto;
+
 pub mod airdrop;
 pub mod bank;
 pub mod config;
 pub mod poll;
+pub mod share_rate;
 pub mod state;
 
 pub static KEY_CONFIG: &[u8] = b"config";
@@ -9,10 +13,24 @@ pub static KEY_STATE: &[u8] = b"state";
 pub static KEY_TMP_POLL_ID: &[u8] = b"tmp_poll_id";
 
 pub static PREFIX_AIRDROP: &[u8] = b"airdrop";
+pub static PREFIX_AIRDROP_ALLOCATION: &[u8] = b"airdrop_allocation";
+pub static PREFIX_AIRDROP_FINISHED: &[u8] = b"airdrop_finished";
+pub static PREFIX_AIRDROP_RESERVE: &[u8] = b"airdrop_reserve";
 pub static PREFIX_AIRDROP_REWARD: &[u8] = b"airdrop_reward";
+pub static PREFIX_AIRDROP_REWARD_SETTLED: &[u8] = b"airdrop_reward_settled";
 pub static PREFIX_BANK: &[u8] = b"bank";
+pub static PREFIX_CONFIG_HISTORY: &[u8] = b"config_history";
 pub static PREFIX_POLL: &[u8] = b"poll";
 pub static PREFIX_POLL_VOTER: &[u8] = b"poll_voter";
 pub static PREFIX_POLL_INDEXER: &[u8] = b"poll_indexer";
 pub static PREFIX_POLL_INDEXER_STATUS: &[u8] = b"status";
 pub static PREFIX_POLL_INDEXER_CATEGORY: &[u8] = b"category";
+pub static PREFIX_SHARE_RATE: &[u8] = b"share_rate";
+
+/// decode_id decodes a big-endian u64 storage key
+pub fn decode_id(k: &[u8]) -> StdResult<u64> {
+    let k: [u8; 8] = k
+        .try_into()
+        .map_err(|_| StdError::invalid_data_size(8, k.len()))?;
+    Ok(u64::from_be_bytes(k))
+}
@@ -2,8 +2,8 @@ use cosmwasm_std::{Binary, CanonicalAddr, StdError, StdResult, Storage, Uint128}
 use cosmwasm_storage::{Bucket, ReadonlyBucket, ReadonlySingleton, Singleton};
 use pylon_token::common::OrderBy;
 use pylon_token::gov_msg::{
-    PollCategory as GovPollCategory, PollStatus as GovPollStatus, VoteOption as GovVoteOption,
-    VoterInfo as GovVoterInfo,
+    PollCategory as GovPollCategory, PollStatus as GovPollStatus,
+    PollTallyMode as GovPollTallyMode, VoteOption as GovVoteOption, VoterInfo as GovVoterInfo,
 };
 use pylon_utils::range::{
     calc_range_end, calc_range_end_addr, calc_range_start, calc_range_start_addr,
@@ -13,7 +13,8 @@ use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt;
 
-use crate::constant::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
+use crate::constant::{DEFAULT_QUERY_LIMIT, MAX_POLL_OPTIONS, MAX_QUERY_LIMIT};
+use crate::error::ContractError;
 
 const MIN_TITLE_LENGTH: usize = 4;
 const MAX_TITLE_LENGTH: usize = 64;
@@ -23,6 +24,9 @@ const MIN_DESC_LENGTH: usize = 4;
 const MAX_DESC_LENGTH: usize = 1024;
 const MIN_LINK_LENGTH: usize = 12;
 const MAX_LINK_LENGTH: usize = 128;
+const MIN_POLL_OPTIONS: usize = 2;
+const MIN_OPTION_NAME_LENGTH: usize = 1;
+const MAX_OPTION_NAME_LENGTH: usize = 64;
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
 pub struct ExecuteData {
@@ -58,6 +62,7 @@ pub enum PollStatus {
     Rejected,
     Executed,
     Failed,
+    Concluded,
 }
 
 impl From<PollStatus> for GovPollStatus {
@@ -68,6 +73,7 @@ impl From<PollStatus> for GovPollStatus {
             PollStatus::Rejected => GovPollStatus::Rejected,
             PollStatus::Executed => GovPollStatus::Executed,
             PollStatus::Failed => GovPollStatus::Failed,
+            PollStatus::Concluded => GovPollStatus::Concluded,
         }
     }
 }
@@ -81,6 +87,7 @@ impl From<GovPollStatus> for PollStatus {
             GovPollStatus::Executed => PollStatus::Executed,
             GovPollStatus::Expired => PollStatus::Rejected,
             GovPollStatus::Failed => PollStatus::Failed,
+            GovPollStatus::Concluded => PollStatus::Concluded,
         }
     }
 }
@@ -142,6 +149,7 @@ pub struct Poll {
     /// Total balance at the end poll
     pub total_balance_at_end_poll: Option<Uint128>,
     pub staked_amount: Option<Uint128>,
+    pub multiple_choice: Option<PollMultipleChoice>,
 }
 
 impl Poll {
@@ -153,6 +161,27 @@ impl Poll {
         ReadonlyBucket::new(storage, super::PREFIX_POLL).load(&id.to_be_bytes())
     }
 
+    /// executable_data returns the messages to run once the poll is passed,
+    /// which are the winning option's messages for multiple-choice polls
+    pub fn executable_data(&self) -> Option<&Vec<ExecuteData>> {
+        match &self.multiple_choice {
+            Some(multiple_choice) => multiple_choice.winning_option.and_then(|option| {
+                multiple_choice.options[option as usize]
+                    .execute_data
+                    .as_ref()
+            }),
+            None => self.execute_data.as_ref(),
+        }
+    }
+
+    /// is_signal returns true if the poll has nothing to execute once passed
+    pub fn is_signal(&self) -> bool {
+        match self.executable_data() {
+            Some(execute_data) => execute_data.is_empty(),
+            None => true,
+        }
+    }
+
     pub fn load_range(
         storage: &dyn Storage,
         start_after: Option<u64>,
@@ -314,11 +343,12 @@ impl Poll {
 
     /* ================= VALIDATOR ================= */
 
-    pub fn validate(&self) -> StdResult<()> {
+    pub fn validate(&self) -> Result<(), ContractError> {
         self.validate_title()?;
         self.validate_category()?;
         self.validate_description()?;
         self.validate_link()?;
+        self.validate_multiple_choice()?;
         Ok(())
     }
 
@@ -369,6 +399,110 @@ impl Poll {
             Ok(())
         }
     }
+
+    /// validate_multiple_choice returns an error if the options are invalid
+    fn validate_multiple_choice(&self) -> Result<(), ContractError> {
+        if let Some(multiple_choice) = &self.multiple_choice {
+            if self.execute_data.is_some() {
+                return Err(ContractError::ExecuteDataPerOption {});
+            }
+            if multiple_choice.options.len() < MIN_POLL_OPTIONS {
+                return Err(ContractError::TooFewPollOptions {});
+            }
+            if multiple_choice.options.len() > MAX_POLL_OPTIONS {
+                return Err(ContractError::TooManyPollOptions {});
+            }
+            for option in multiple_choice.options.iter() {
+                if option.name.len() < MIN_OPTION_NAME_LENGTH {
+                    return Err(ContractError::PollOptionNameTooShort {});
+                }
+                if option.name.len() > MAX_OPTION_NAME_LENGTH {
+                    return Err(ContractError::PollOptionNameTooLong {});
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PollTallyMode {
+    Plurality,
+    Approval,
+}
+
+impl From<PollTallyMode> for GovPollTallyMode {
+    fn from(mode: PollTallyMode) -> Self {
+        match mode {
+            PollTallyMode::Plurality => GovPollTallyMode::Plurality,
+            PollTallyMode::Approval => GovPollTallyMode::Approval,
+        }
+    }
+}
+
+impl From<GovPollTallyMode> for PollTallyMode {
+    fn from(mode: GovPollTallyMode) -> Self {
+        match mode {
+            GovPollTallyMode::Plurality => PollTallyMode::Plurality,
+            GovPollTallyMode::Approval => PollTallyMode::Approval,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PollOption {
+    pub name: String,
+    pub execute_data: Option<Vec<ExecuteData>>,
+    pub votes: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PollMultipleChoice {
+    pub mode: PollTallyMode,
+    pub options: Vec<PollOption>,
+    /// Total balance which participated in the poll
+    pub voted_amount: Uint128,
+    pub winning_option: Option<u32>,
+}
+
+impl PollMultipleChoice {
+    /// cast_vote adds the amount to each of the chosen options
+    pub fn cast_vote(&mut self, options: &[u32], amount: Uint128) -> Result<(), ContractError> {
+        if options.is_empty()
+            || (self.mode == PollTallyMode::Plurality && options.len() != 1)
+            || options.iter().enumerate().any(|(i, option)| {
+                *option as usize >= self.options.len() || options[..i].contains(option)
+            })
+        {
+            return Err(ContractError::InvalidVoteOption {});
+        }
+
+        for option in options.iter() {
+            self.options[*option as usize].votes += amount;
+        }
+        self.voted_amount += amount;
+
+        Ok(())
+    }
+
+    /// leading_option returns the option with the most votes, or None on a tie
+    pub fn leading_option(&self) -> Option<u32> {
+        let max_votes = self.options.iter().map(|option| option.votes).max()?;
+        if max_votes.is_zero() {
+            return None;
+        }
+
+        let mut leaders = self
+            .options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| option.votes == max_votes);
+        match (leaders.next(), leaders.next()) {
+            (Some((index, _)), None) => Some(index as u32),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -376,6 +510,7 @@ impl Poll {
 pub enum VoteOption {
     Yes,
     No,
+    Options(Vec<u32>),
 }
 
 impl From<VoteOption> for GovVoteOption {
@@ -383,6 +518,7 @@ impl From<VoteOption> for GovVoteOption {
         match option {
             VoteOption::Yes => GovVoteOption::Yes,
             VoteOption::No => GovVoteOption::No,
+            VoteOption::Options(options) => GovVoteOption::Options(options),
         }
     }
 }
@@ -392,16 +528,25 @@ impl From<GovVoteOption> for VoteOption {
         match option {
             GovVoteOption::Yes {} => VoteOption::Yes,
             GovVoteOption::No {} => VoteOption::No,
+            GovVoteOption::Options(options) => VoteOption::Options(options),
         }
     }
 }
 
 impl fmt::Display for VoteOption {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if *self == VoteOption::Yes {
-            write!(f, "yes")
-        } else {
-            write!(f, "no")
+        match self {
+            VoteOption::Yes => write!(f, "yes"),
+            VoteOption::No => write!(f, "no"),
+            VoteOption::Options(options) => write!(
+                f,
+                "{}",
+                options
+                    .iter()
+                    .map(|option| option.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
         }
     }
 }
@@ -0,0 +1,67 @@
+use cosmwasm_std::{Decimal, StdResult, Storage, Uint128};
+use cosmwasm_storage::{Bucket, ReadonlyBucket};
+use pylon_token::common::OrderBy;
+use pylon_utils::range::{calc_range_end, calc_range_start};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::constant::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
+
+/// balance_per_share returns how many staked tokens a share is worth, 1 before anything is staked
+pub fn balance_per_share(balance: Uint128, share: Uint128) -> Decimal {
+    if share.is_zero() {
+        Decimal::one()
+    } else {
+        Decimal::from_ratio(balance, share)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ShareRateCheckpoint {
+    pub height: u64,
+    pub balance: Uint128,
+    pub share: Uint128,
+}
+
+impl ShareRateCheckpoint {
+    pub fn balance_per_share(&self) -> Decimal {
+        balance_per_share(self.balance, self.share)
+    }
+
+    /// load_last returns the latest checkpoint with its time
+    pub fn load_last(storage: &dyn Storage) -> StdResult<Option<(u64, ShareRateCheckpoint)>> {
+        Ok(Self::load_range(storage, None, Some(1), Some(OrderBy::Desc))?.pop())
+    }
+
+    pub fn load_range(
+        storage: &dyn Storage,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    ) -> StdResult<Vec<(u64, ShareRateCheckpoint)>> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        let (start, end, order_by) = match order_by {
+            Some(OrderBy::Asc) => (calc_range_start(start_after), None, OrderBy::Asc),
+            _ => (None, calc_range_end(start_after), OrderBy::Desc),
+        };
+
+        ReadonlyBucket::new(storage, super::PREFIX_SHARE_RATE)
+            .range(start.as_deref(), end.as_deref(), order_by.into())
+            .take(limit)
+            .map(
+                |item: StdResult<(Vec<u8>, ShareRateCheckpoint)>| -> StdResult<(u64, ShareRateCheckpoint)> {
+                    let (k, v) = item?;
+                    Ok((super::decode_id(&k)?, v))
+                },
+            )
+            .collect()
+    }
+
+    pub fn save(
+        storage: &mut dyn Storage,
+        time: &u64,
+        checkpoint: &ShareRateCheckpoint,
+    ) -> StdResult<()> {
+        Bucket::new(storage, super::PREFIX_SHARE_RATE).save(&time.to_be_bytes(), checkpoint)
+    }
+}
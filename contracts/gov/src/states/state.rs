@@ -10,6 +10,9 @@ pub struct State {
     pub total_deposit: Uint128,
     pub total_airdrop_count: u64,
     pub airdrop_update_candidates: Vec<u64>,
+    /// voting tokens deposited for airdrops, neither staked nor deposited to polls
+    #[serde(default)]
+    pub airdrop_fund: Uint128,
 }
 
 impl State {
@@ -30,6 +30,7 @@ fn success() {
     let mut deps = mock_deps();
     instantiate::default(&mut deps);
     super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 1000);
+    super::airdrop_instantiate::fund(&mut deps, TEST_TOKEN, 3000u128);
 
     let response = exec(
         &mut deps,
@@ -64,6 +65,7 @@ fn fail_unauthorized() {
     let mut deps = mock_deps();
     instantiate::default(&mut deps);
     super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 1000);
+    super::airdrop_instantiate::fund(&mut deps, TEST_TOKEN, 3000u128);
 
     match exec(
         &mut deps,
@@ -0,0 +1,146 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, Api, Env, MessageInfo, Uint128};
+
+use crate::error::ContractError;
+use crate::executions::airdrop::allocate_batch;
+use crate::executions::ExecuteResult;
+use crate::states::airdrop::{Airdrop, Reserve, Reward};
+use crate::testing::{
+    instantiate, mock_deps, MockDeps, TEST_CREATOR, TEST_TOKEN, TEST_VOTER, TEST_VOTER_2,
+};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    airdrop_id: u64,
+    allocations: Vec<(String, Uint128)>,
+) -> ExecuteResult {
+    allocate_batch(deps.as_mut(), env, info, airdrop_id, allocations)
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 1000u128);
+    super::airdrop_instantiate::fund(&mut deps, TEST_TOKEN, 3000u128);
+
+    let response = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        vec![
+            (TEST_VOTER.to_string(), Uint128::from(100u128)),
+            (TEST_VOTER_2.to_string(), Uint128::from(200u128)),
+            (TEST_VOTER.to_string(), Uint128::from(300u128)),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_allocate_batch"),
+            attr("airdrop_id", 0.to_string()),
+            attr("count", 3.to_string()),
+            attr("total_amount", Uint128::from(600u128)),
+        ]
+    );
+
+    for (recipient, amount) in [(TEST_VOTER, 400u128), (TEST_VOTER_2, 200u128)] {
+        let reward = Reward::load(
+            &deps.storage,
+            &deps.api.addr_validate(recipient).unwrap(),
+            &0u64,
+        )
+        .unwrap();
+        assert_eq!(reward.reward, Uint128::from(amount));
+    }
+
+    let airdrop = Airdrop::load(&deps.storage, &0u64).unwrap();
+    assert_eq!(airdrop.state.funded, Uint128::from(1600u128));
+    assert_eq!(
+        Reserve::load(&deps.storage, &airdrop.config.reward_token).unwrap(),
+        Uint128::from(1600u128)
+    );
+}
+
+#[test]
+fn fail_not_funded() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 1000u128);
+    super::airdrop_instantiate::fund(&mut deps, TEST_TOKEN, 1500u128);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        vec![
+            (TEST_VOTER.to_string(), Uint128::from(300u128)),
+            (TEST_VOTER_2.to_string(), Uint128::from(300u128)),
+        ],
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropNotFunded {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 1000u128);
+    super::airdrop_instantiate::fund(&mut deps, TEST_TOKEN, 3000u128);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_VOTER, &[]),
+        0,
+        vec![(TEST_VOTER.to_string(), Uint128::from(100u128))],
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_invalid_entries() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 1000u128);
+    super::airdrop_instantiate::fund(&mut deps, TEST_TOKEN, 3000u128);
+
+    for (airdrop_id, allocations, expected) in [
+        (0, vec![], ContractError::DataShouldBeGiven {}),
+        (
+            0,
+            vec![
+                (TEST_VOTER.to_string(), Uint128::from(100u128)),
+                (TEST_VOTER_2.to_string(), Uint128::zero()),
+            ],
+            ContractError::InvalidAllocationAmount {},
+        ),
+        (
+            1,
+            vec![(TEST_VOTER.to_string(), Uint128::from(100u128))],
+            ContractError::AirdropNotFound {},
+        ),
+    ] {
+        match exec(
+            &mut deps,
+            mock_env(),
+            mock_info(TEST_CREATOR, &[]),
+            airdrop_id,
+            allocations,
+        ) {
+            Ok(_) => panic!("Must return error"),
+            Err(e) => assert_eq!(e, expected),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{attr, to_binary, Api, CosmosMsg, Env, MessageInfo, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+
+use crate::error::ContractError;
+use crate::executions::airdrop::cancel;
+use crate::executions::ExecuteResult;
+use crate::states::airdrop::{Airdrop, Reward};
+use crate::testing::{
+    instantiate, mock_deps, mock_env_height, MockDeps, TEST_CREATOR, TEST_TOKEN, TEST_VOTER,
+    TEST_VOTER_2, VOTING_TOKEN,
+};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    airdrop_id: u64,
+    refund_to: Option<String>,
+) -> ExecuteResult {
+    cancel(deps.as_mut(), env, info, airdrop_id, refund_to)
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let (env, _, _) = super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+    let now = env.block.time.seconds();
+
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(100u128))],
+    )]);
+
+    super::staking_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        Uint128::from(100u128),
+    )
+    .unwrap();
+
+    let response = exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 43200),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        Some(TEST_VOTER_2.to_string()),
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: TEST_TOKEN.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: TEST_VOTER_2.to_string(),
+                amount: Uint128::from(43200u128),
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_cancel"),
+            attr("airdrop_id", "0"),
+            attr("refund_to", TEST_VOTER_2),
+            attr("refund_amount", "43200"),
+            attr("period", "43200"),
+        ]
+    );
+
+    let airdrop = Airdrop::load(&deps.storage, &0).unwrap();
+    assert!(airdrop.is_final());
+    assert_eq!(airdrop.state.funded, Uint128::from(43200u128));
+
+    // rewards accrued before the cancellation are still claimable
+    super::airdrop_update::exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 86400),
+        mock_info(TEST_VOTER, &[]),
+        Some(TEST_VOTER.to_string()),
+    )
+    .unwrap();
+
+    let reward = Reward::load(
+        &deps.storage,
+        &deps.api.addr_validate(TEST_VOTER).unwrap(),
+        &0,
+    )
+    .unwrap();
+    assert_eq!(reward.reward, Uint128::from(43200u128));
+}
+
+#[test]
+fn success_refund_to_sender() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+
+    let response = exec(&mut deps, mock_env(), mock_info(TEST_CREATOR, &[]), 0, None).unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_cancel"),
+            attr("airdrop_id", "0"),
+            attr("refund_to", TEST_CREATOR),
+            attr("refund_amount", "86400"),
+            attr("period", "0"),
+        ]
+    );
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+
+    match exec(&mut deps, mock_env(), mock_info(TEST_VOTER, &[]), 0, None) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_finished() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let (env, _, _) = super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+
+    match exec(
+        &mut deps,
+        mock_env_height(env.block.height, env.block.time.seconds() + 86400),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        None,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropFinished {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
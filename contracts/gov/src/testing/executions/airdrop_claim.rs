@@ -1,21 +1,38 @@
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-use cosmwasm_std::{attr, Env, MessageInfo, Uint128};
+use cosmwasm_std::{attr, coin, to_binary, BankMsg, CosmosMsg, Env, MessageInfo, Uint128, WasmMsg};
+use pylon_token::gov_msg::{AirdropMsg, ExecuteMsg};
+use terraswap::asset::AssetInfo;
 
-use crate::executions::airdrop::claim_internal;
+use crate::error::ContractError;
+use crate::executions::airdrop::{claim, claim_internal};
 use crate::executions::ExecuteResult;
+use crate::states::airdrop::Airdrop;
+use crate::testing::mock_querier::mock_dependencies;
 use crate::testing::{
-    instantiate, mock_deps, mock_env_height, MockDeps, TEST_TOKEN, TEST_VOTER, VOTING_TOKEN,
+    instantiate, mock_deps, mock_env_height, MockDeps, TEST_CREATOR, TEST_TOKEN, TEST_VOTER,
+    TEST_VOTER_2, VOTING_TOKEN,
 };
 
-#[allow(dead_code)]
 pub fn exec(
     deps: &mut MockDeps,
     env: Env,
     info: MessageInfo,
     sender: String,
     airdrop_id: u64,
+    recipient: Option<String>,
 ) -> ExecuteResult {
-    claim_internal(deps.as_mut(), env, info, sender, airdrop_id)
+    claim_internal(deps.as_mut(), env, info, sender, airdrop_id, recipient)
+}
+
+pub fn exec_claim(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    target: Option<String>,
+    airdrop_ids: Option<Vec<u64>>,
+    recipient: Option<String>,
+) -> ExecuteResult {
+    claim(deps.as_mut(), env, info, target, airdrop_ids, recipient)
 }
 
 #[test]
@@ -52,6 +69,7 @@ fn success() {
         mock_info(MOCK_CONTRACT_ADDR, &[]),
         TEST_VOTER.to_string(),
         0,
+        None,
     )
     .unwrap();
     assert_eq!(
@@ -59,8 +77,275 @@ fn success() {
         vec![
             attr("action", "airdrop_claim"),
             attr("target", TEST_VOTER),
+            attr("recipient", TEST_VOTER),
             attr("token", TEST_TOKEN),
             attr("amount", Uint128::from(86400u128))
         ]
     );
+
+    let airdrop = Airdrop::load(&deps.storage, &0).unwrap();
+    assert_eq!(airdrop.state.distributed, Uint128::from(86400u128));
+}
+
+#[test]
+fn success_native_token() {
+    let mut deps = mock_dependencies(&[coin(86400, "uusd")]);
+    instantiate::default(&mut deps);
+
+    let env = mock_env();
+    super::airdrop_instantiate::exec(
+        &mut deps,
+        env.clone(),
+        mock_info(TEST_CREATOR, &[]),
+        env.block.time.seconds(),
+        86400,
+        AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        Uint128::from(86400u128),
+    )
+    .unwrap();
+
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(100u128))],
+    )]);
+
+    super::staking_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        Uint128::from(100u128),
+    )
+    .unwrap();
+
+    super::airdrop_update::exec(
+        &mut deps,
+        mock_env_height(env.block.height, env.block.time.seconds() + 86400 * 2),
+        mock_info(TEST_VOTER, &[]),
+        Some(TEST_VOTER.to_string()),
+    )
+    .unwrap();
+
+    let response = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        0,
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: TEST_VOTER.to_string(),
+            amount: vec![coin(86400, "uusd")],
+        })
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_claim"),
+            attr("target", TEST_VOTER),
+            attr("recipient", TEST_VOTER),
+            attr("token", "uusd"),
+            attr("amount", Uint128::from(86400u128))
+        ]
+    );
+}
+
+fn setup_two_airdrops(deps: &mut MockDeps) -> Env {
+    instantiate::default(deps);
+    let (env, _, _) = super::airdrop_instantiate::default(deps, TEST_TOKEN, 86400);
+    super::airdrop_instantiate::fund(deps, TEST_TOKEN, 86400 * 2);
+    super::airdrop_instantiate::exec(
+        deps,
+        env.clone(),
+        mock_info(TEST_CREATOR, &[]),
+        env.block.time.seconds(),
+        86400,
+        super::airdrop_instantiate::token(TEST_TOKEN),
+        Uint128::from(86400u128),
+    )
+    .unwrap();
+
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(100u128))],
+    )]);
+
+    super::staking_deposit::exec(
+        deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        Uint128::from(100u128),
+    )
+    .unwrap();
+
+    env
+}
+
+fn claim_internal_msg(airdrop_id: u64, recipient: Option<String>) -> CosmosMsg {
+    CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: MOCK_CONTRACT_ADDR.to_string(),
+        msg: to_binary(&ExecuteMsg::Airdrop(AirdropMsg::ClaimInternal {
+            sender: TEST_VOTER.to_string(),
+            airdrop_id,
+            recipient,
+        }))
+        .unwrap(),
+        funds: vec![],
+    })
+}
+
+#[test]
+fn claim_all() {
+    let mut deps = mock_deps();
+    let env = setup_two_airdrops(&mut deps);
+
+    // rewards are listed once they have been settled
+    super::airdrop_update::exec(
+        &mut deps,
+        mock_env_height(env.block.height, env.block.time.seconds() + 43200),
+        mock_info(TEST_VOTER, &[]),
+        Some(TEST_VOTER.to_string()),
+    )
+    .unwrap();
+
+    let response = exec_claim(
+        &mut deps,
+        mock_env_height(env.block.height, env.block.time.seconds() + 86400),
+        mock_info(TEST_VOTER, &[]),
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    let messages: Vec<CosmosMsg> = response.messages.into_iter().map(|x| x.msg).collect();
+    assert_eq!(
+        messages[1..],
+        [claim_internal_msg(1, None), claim_internal_msg(0, None)]
+    );
+}
+
+#[test]
+fn claim_chosen_airdrops_to_recipient() {
+    let mut deps = mock_deps();
+    let env = setup_two_airdrops(&mut deps);
+
+    let response = exec_claim(
+        &mut deps,
+        mock_env_height(env.block.height, env.block.time.seconds() + 86400),
+        mock_info(TEST_VOTER, &[]),
+        None,
+        Some(vec![1, 1]),
+        Some(TEST_VOTER_2.to_string()),
+    )
+    .unwrap();
+    let messages: Vec<CosmosMsg> = response.messages.into_iter().map(|x| x.msg).collect();
+    assert_eq!(
+        messages[1..],
+        [claim_internal_msg(1, Some(TEST_VOTER_2.to_string()))]
+    );
+
+    let response = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        1,
+        Some(TEST_VOTER_2.to_string()),
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_claim"),
+            attr("target", TEST_VOTER),
+            attr("recipient", TEST_VOTER_2),
+            attr("token", TEST_TOKEN),
+            attr("amount", Uint128::zero())
+        ]
+    );
+}
+
+#[test]
+fn fail_claim_to_recipient_on_behalf() {
+    let mut deps = mock_deps();
+    setup_two_airdrops(&mut deps);
+
+    match exec_claim(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_VOTER_2, &[]),
+        Some(TEST_VOTER.to_string()),
+        None,
+        Some(TEST_VOTER_2.to_string()),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_claim_airdrop_not_found() {
+    let mut deps = mock_deps();
+    setup_two_airdrops(&mut deps);
+
+    match exec_claim(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_VOTER, &[]),
+        None,
+        Some(vec![0, 2]),
+        None,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropNotFound {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_invalid_address() {
+    let mut deps = mock_deps();
+    setup_two_airdrops(&mut deps);
+
+    for (target, recipient) in [(Some("x".to_string()), None), (None, Some("x".to_string()))] {
+        match exec_claim(
+            &mut deps,
+            mock_env(),
+            mock_info(TEST_VOTER, &[]),
+            target,
+            None,
+            recipient,
+        ) {
+            Ok(_) => panic!("Must return error"),
+            Err(ContractError::InvalidAddress(address)) => assert_eq!(address, "x"),
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+}
+
+#[test]
+fn fail_claim_internal_airdrop_not_found() {
+    let mut deps = mock_deps();
+    setup_two_airdrops(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        2,
+        None,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropNotFound {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
 }
@@ -0,0 +1,138 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{attr, from_binary, Api, Env, MessageInfo, Uint128};
+use pylon_token::gov_resp::StateResponse;
+
+use crate::executions::airdrop::compact;
+use crate::executions::ExecuteResult;
+use crate::queries::state::query_state;
+use crate::states::airdrop::Reward;
+use crate::testing::{
+    instantiate, mock_deps, mock_env_height, MockDeps, TEST_CREATOR, TEST_TOKEN, TEST_VOTER,
+    VOTING_TOKEN,
+};
+
+pub fn exec(deps: &mut MockDeps, env: Env, info: MessageInfo) -> ExecuteResult {
+    compact(deps.as_mut(), env, info)
+}
+
+fn stake(deps: &mut MockDeps, total_balance: u128, amount: u128) {
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(
+            &MOCK_CONTRACT_ADDR.to_string(),
+            &Uint128::from(total_balance),
+        )],
+    )]);
+
+    super::staking_deposit::exec(
+        deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        Uint128::from(amount),
+    )
+    .unwrap();
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let (env, _, _) = super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400); // #0
+    let now = env.block.time.seconds();
+    super::airdrop_instantiate::fund(&mut deps, TEST_TOKEN, 86400 * 2);
+    super::airdrop_instantiate::exec(
+        &mut deps,
+        env.clone(),
+        mock_info(TEST_CREATOR, &[]),
+        now,
+        86400 * 2,
+        super::airdrop_instantiate::token(TEST_TOKEN),
+        Uint128::from(86400u128),
+    )
+    .unwrap(); // #1
+
+    stake(&mut deps, 100, 100);
+
+    // nothing finished yet
+    let response = exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 100),
+        mock_info(TEST_VOTER, &[]),
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_compact"),
+            attr("pruned", "[]"),
+            attr("candidates", "2"),
+        ]
+    );
+
+    let response = exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 86400),
+        mock_info(TEST_VOTER, &[]),
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_compact"),
+            attr("pruned", "[0]"),
+            attr("candidates", "1"),
+        ]
+    );
+
+    let response = query_state(deps.as_ref(), mock_env()).unwrap();
+    let response: StateResponse = from_binary(&response).unwrap();
+    assert_eq!(response.airdrop_update_candidates, vec![1]);
+    assert_eq!(response.airdrop_update_candidate_count, 1);
+}
+
+#[test]
+fn settle_pruned_airdrop_before_share_change() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let (env, _, _) = super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+    let now = env.block.time.seconds();
+
+    stake(&mut deps, 100, 100);
+
+    exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 86400),
+        mock_info(TEST_VOTER, &[]),
+    )
+    .unwrap();
+
+    // stake more after the airdrop has been pruned
+    super::airdrop_update::exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 86400 + 10),
+        mock_info(TEST_VOTER, &[]),
+        Some(TEST_VOTER.to_string()),
+    )
+    .unwrap();
+    stake(&mut deps, 200, 100);
+
+    super::airdrop_update::exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 86400 + 20),
+        mock_info(TEST_VOTER, &[]),
+        Some(TEST_VOTER.to_string()),
+    )
+    .unwrap();
+
+    // rewards must be calculated with the share during the airdrop
+    let reward = Reward::load(
+        &deps.storage,
+        &deps.api.addr_validate(TEST_VOTER).unwrap(),
+        &0,
+    )
+    .unwrap();
+    assert_eq!(reward.reward, Uint128::from(86400u128));
+}
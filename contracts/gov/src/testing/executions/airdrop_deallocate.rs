@@ -30,6 +30,7 @@ fn success() {
     let mut deps = mock_deps();
     instantiate::default(&mut deps);
     super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 1000u128);
+    super::airdrop_instantiate::fund(&mut deps, TEST_TOKEN, 3000u128);
     super::airdrop_allocate::exec(
         &mut deps,
         mock_env(),
@@ -73,6 +74,7 @@ fn fail_unauthorized() {
     let mut deps = mock_deps();
     instantiate::default(&mut deps);
     super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 1000u128);
+    super::airdrop_instantiate::fund(&mut deps, TEST_TOKEN, 3000u128);
     super::airdrop_allocate::exec(
         &mut deps,
         mock_env(),
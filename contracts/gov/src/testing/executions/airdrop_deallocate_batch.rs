@@ -0,0 +1,121 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, Api, Env, MessageInfo, Uint128};
+
+use crate::error::ContractError;
+use crate::executions::airdrop::deallocate_batch;
+use crate::executions::ExecuteResult;
+use crate::states::airdrop::{Airdrop, Reserve, Reward};
+use crate::testing::{
+    instantiate, mock_deps, MockDeps, TEST_CREATOR, TEST_TOKEN, TEST_VOTER, TEST_VOTER_2,
+};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    airdrop_id: u64,
+    deallocations: Vec<(String, Uint128)>,
+) -> ExecuteResult {
+    deallocate_batch(deps.as_mut(), env, info, airdrop_id, deallocations)
+}
+
+fn setup(deps: &mut MockDeps) {
+    instantiate::default(deps);
+    super::airdrop_instantiate::default(deps, TEST_TOKEN, 1000u128);
+    super::airdrop_instantiate::fund(deps, TEST_TOKEN, 3000u128);
+    super::airdrop_allocate_batch::exec(
+        deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        vec![
+            (TEST_VOTER.to_string(), Uint128::from(1234u128)),
+            (TEST_VOTER_2.to_string(), Uint128::from(100u128)),
+        ],
+    )
+    .unwrap();
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    setup(&mut deps);
+
+    let response = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        vec![
+            (TEST_VOTER.to_string(), Uint128::from(34u128)),
+            (TEST_VOTER_2.to_string(), Uint128::from(100u128)),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_deallocate_batch"),
+            attr("airdrop_id", 0.to_string()),
+            attr("count", 2.to_string()),
+            attr("total_amount", Uint128::from(134u128)),
+        ]
+    );
+
+    for (recipient, amount) in [(TEST_VOTER, 1200u128), (TEST_VOTER_2, 0u128)] {
+        let reward = Reward::load(
+            &deps.storage,
+            &deps.api.addr_validate(recipient).unwrap(),
+            &0u64,
+        )
+        .unwrap();
+        assert_eq!(reward.reward, Uint128::from(amount));
+    }
+
+    let airdrop = Airdrop::load(&deps.storage, &0u64).unwrap();
+    assert_eq!(airdrop.state.funded, Uint128::from(2200u128));
+    assert_eq!(
+        Reserve::load(&deps.storage, &airdrop.config.reward_token).unwrap(),
+        Uint128::from(2200u128)
+    );
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    setup(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_VOTER, &[]),
+        0,
+        vec![(TEST_VOTER.to_string(), Uint128::from(34u128))],
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_insufficient_reward() {
+    let mut deps = mock_deps();
+    setup(&mut deps);
+
+    // the whole batch is reverted if any entry fails
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        vec![
+            (TEST_VOTER.to_string(), Uint128::from(34u128)),
+            (TEST_VOTER_2.to_string(), Uint128::from(101u128)),
+        ],
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InsufficientReward {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -0,0 +1,142 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{attr, Api, Decimal, Env, MessageInfo, Uint128};
+
+use crate::error::ContractError;
+use crate::executions::airdrop::extend;
+use crate::executions::ExecuteResult;
+use crate::states::airdrop::{Airdrop, Reward};
+use crate::testing::{
+    instantiate, mock_deps, mock_env_height, MockDeps, TEST_CREATOR, TEST_TOKEN, TEST_VOTER,
+    VOTING_TOKEN,
+};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    airdrop_id: u64,
+    additional_period: u64,
+) -> ExecuteResult {
+    extend(deps.as_mut(), env, info, airdrop_id, additional_period)
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let (env, _, _) = super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+    let now = env.block.time.seconds();
+
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(100u128))],
+    )]);
+
+    super::staking_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        Uint128::from(100u128),
+    )
+    .unwrap();
+
+    let response = exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 43200),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        43200,
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_extend"),
+            attr("airdrop_id", "0"),
+            attr("period", "129600"),
+            attr("reward_rate", "0.5"),
+        ]
+    );
+
+    let airdrop = Airdrop::load(&deps.storage, &0).unwrap();
+    assert_eq!(airdrop.config.period, 129600);
+    assert_eq!(
+        airdrop.config.reward_rate,
+        Decimal::from_ratio(1u128, 2u128)
+    );
+    assert_eq!(airdrop.state.funded, Uint128::from(86400u128));
+
+    super::airdrop_update::exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 129600),
+        mock_info(TEST_VOTER, &[]),
+        Some(TEST_VOTER.to_string()),
+    )
+    .unwrap();
+
+    let reward = Reward::load(
+        &deps.storage,
+        &deps.api.addr_validate(TEST_VOTER).unwrap(),
+        &0,
+    )
+    .unwrap();
+    assert_eq!(reward.reward, Uint128::from(86400u128));
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+
+    match exec(&mut deps, mock_env(), mock_info(TEST_VOTER, &[]), 0, 100) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_finished() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let (env, _, _) = super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+
+    match exec(
+        &mut deps,
+        mock_env_height(env.block.height, env.block.time.seconds() + 86400),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        100,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropFinished {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_invalid_period() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+
+    match exec(&mut deps, mock_env(), mock_info(TEST_CREATOR, &[]), 0, 0) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidAirdropPeriod {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        u64::MAX,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropPeriodOverflow {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -1,12 +1,18 @@
-use cosmwasm_std::testing::{mock_env, mock_info};
-use cosmwasm_std::{attr, Api, Decimal, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{attr, coin, to_binary, Decimal, Env, MessageInfo, Response, Uint128};
+use cw20::Cw20ReceiveMsg;
+use pylon_token::gov_msg::Cw20HookMsg;
+use terraswap::asset::AssetInfo;
 
 use crate::error::ContractError;
 use crate::executions::airdrop::instantiate;
-use crate::executions::ExecuteResult;
-use crate::states::airdrop::{Airdrop, Config};
+use crate::executions::{receive, ExecuteResult};
+use crate::states::airdrop::{Airdrop, Config, Reserve};
 use crate::states::state::State;
-use crate::testing::{instantiate, mock_deps, MockDeps, TEST_CREATOR, TEST_TOKEN, TEST_VOTER};
+use crate::testing::mock_querier::mock_dependencies;
+use crate::testing::{
+    instantiate, mock_deps, MockDeps, TEST_CREATOR, TEST_TOKEN, TEST_VOTER, VOTING_TOKEN,
+};
 
 pub fn exec(
     deps: &mut MockDeps,
@@ -14,7 +20,7 @@ pub fn exec(
     info: MessageInfo,
     start: u64,
     period: u64,
-    reward_token: String,
+    reward_token: AssetInfo,
     reward_amount: Uint128,
 ) -> ExecuteResult {
     instantiate(
@@ -28,17 +34,32 @@ pub fn exec(
     )
 }
 
+pub fn token(contract_addr: &str) -> AssetInfo {
+    AssetInfo::Token {
+        contract_addr: contract_addr.to_string(),
+    }
+}
+
+pub fn fund(deps: &mut MockDeps, token: &str, amount: u128) {
+    deps.querier.with_token_balances(&[(
+        &token.to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(amount))],
+    )]);
+}
+
 pub fn default(deps: &mut MockDeps, token: &str, amount: u128) -> (Env, MessageInfo, Response) {
     let env = mock_env();
     let info = mock_info(TEST_CREATOR, &[]);
 
+    fund(deps, token, amount);
+
     let response = exec(
         deps,
         env.clone(),
         info.clone(),
         env.block.time.seconds(),
         86400,
-        token.to_string(),
+        self::token(token),
         Uint128::from(amount),
     )
     .unwrap();
@@ -50,6 +71,7 @@ pub fn default(deps: &mut MockDeps, token: &str, amount: u128) -> (Env, MessageI
 fn success() {
     let mut deps = mock_deps();
     instantiate::default(&mut deps);
+    fund(&mut deps, TEST_TOKEN, 1000);
 
     let response = exec(
         &mut deps,
@@ -57,7 +79,7 @@ fn success() {
         mock_info(TEST_CREATOR, &[]),
         100,
         200,
-        TEST_TOKEN.to_string(),
+        token(TEST_TOKEN),
         Uint128::from(1000u128),
     )
     .unwrap();
@@ -81,10 +103,12 @@ fn success() {
         Config {
             start: 100,
             period: 200,
-            reward_token: deps.api.addr_validate(TEST_TOKEN).unwrap(),
+            reward_token: token(TEST_TOKEN),
             reward_rate: Decimal::from_ratio(1000u128, 200u128)
         }
-    )
+    );
+    assert_eq!(airdrop.state.funded, Uint128::from(1000u128));
+    assert_eq!(airdrop.state.distributed, Uint128::zero());
 }
 
 #[test]
@@ -98,7 +122,7 @@ fn fail_unauthorized() {
         mock_info(TEST_VOTER, &[]),
         100,
         200,
-        TEST_TOKEN.to_string(),
+        token(TEST_TOKEN),
         Uint128::from(1000u128),
     ) {
         Ok(_) => panic!("Must return error"),
@@ -106,3 +130,152 @@ fn fail_unauthorized() {
         Err(e) => panic!("Unexpected error: {:?}", e),
     }
 }
+
+#[test]
+fn fail_not_funded() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    fund(&mut deps, TEST_TOKEN, 1500);
+
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        100,
+        200,
+        token(TEST_TOKEN),
+        Uint128::from(1000u128),
+    )
+    .unwrap();
+
+    // 1000 of 1500 is already reserved by the first airdrop
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        100,
+        200,
+        token(TEST_TOKEN),
+        Uint128::from(1000u128),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropNotFunded {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn voting_token() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    // staked balance cannot be airdropped
+    fund(&mut deps, VOTING_TOKEN, 1000);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        100,
+        200,
+        token(VOTING_TOKEN),
+        Uint128::from(1000u128),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropNotFunded {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+
+    fund(&mut deps, VOTING_TOKEN, 2000);
+    let response = receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(VOTING_TOKEN, &[]),
+        Cw20ReceiveMsg {
+            sender: TEST_CREATOR.to_string(),
+            amount: Uint128::from(1000u128),
+            msg: to_binary(&Cw20HookMsg::FundAirdrop {}).unwrap(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_fund"),
+            attr("amount", "1000"),
+            attr("airdrop_fund", "1000"),
+        ]
+    );
+
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        100,
+        200,
+        token(VOTING_TOKEN),
+        Uint128::from(1000u128),
+    )
+    .unwrap();
+    assert_eq!(
+        Reserve::load(deps.as_ref().storage, &token(VOTING_TOKEN)).unwrap(),
+        Uint128::from(1000u128)
+    );
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        100,
+        200,
+        token(VOTING_TOKEN),
+        Uint128::from(1u128),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropNotFunded {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn success_native_token() {
+    let mut deps = mock_dependencies(&[coin(1000, "uusd")]);
+    instantiate::default(&mut deps);
+
+    let response = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        100,
+        200,
+        AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        Uint128::from(1000u128),
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_instantiate"),
+            attr("airdrop_id", 0.to_string()),
+            attr("reward_token", "uusd"),
+            attr("reward_amount", Uint128::from(1000u128))
+        ]
+    );
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        100,
+        200,
+        AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        Uint128::from(1u128),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropNotFunded {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -0,0 +1,148 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{attr, Api, Decimal, Env, MessageInfo, Uint128};
+
+use crate::error::ContractError;
+use crate::executions::airdrop::top_up;
+use crate::executions::ExecuteResult;
+use crate::states::airdrop::{Airdrop, Reward};
+use crate::testing::{
+    instantiate, mock_deps, mock_env_height, MockDeps, TEST_CREATOR, TEST_TOKEN, TEST_VOTER,
+    VOTING_TOKEN,
+};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    airdrop_id: u64,
+    amount: Uint128,
+) -> ExecuteResult {
+    top_up(deps.as_mut(), env, info, airdrop_id, amount)
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let (env, _, _) = super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+    let now = env.block.time.seconds();
+
+    deps.querier.with_token_balances(&[
+        (
+            &VOTING_TOKEN.to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(100u128))],
+        ),
+        (
+            &TEST_TOKEN.to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(129600u128))],
+        ),
+    ]);
+
+    super::staking_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        Uint128::from(100u128),
+    )
+    .unwrap();
+
+    let response = exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 43200),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        Uint128::from(43200u128),
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_top_up"),
+            attr("airdrop_id", "0"),
+            attr("amount", "43200"),
+            attr("reward_rate", "2"),
+        ]
+    );
+
+    let airdrop = Airdrop::load(&deps.storage, &0).unwrap();
+    assert_eq!(
+        airdrop.config.reward_rate,
+        Decimal::from_ratio(2u128, 1u128)
+    );
+    assert_eq!(airdrop.state.funded, Uint128::from(129600u128));
+
+    super::airdrop_update::exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 86400),
+        mock_info(TEST_VOTER, &[]),
+        Some(TEST_VOTER.to_string()),
+    )
+    .unwrap();
+
+    let reward = Reward::load(
+        &deps.storage,
+        &deps.api.addr_validate(TEST_VOTER).unwrap(),
+        &0,
+    )
+    .unwrap();
+    assert_eq!(reward.reward, Uint128::from(129600u128));
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_VOTER, &[]),
+        0,
+        Uint128::from(100u128),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_not_funded() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        Uint128::from(100u128),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropNotFunded {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_finished() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let (env, _, _) = super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+    super::airdrop_instantiate::fund(&mut deps, TEST_TOKEN, 86500);
+
+    match exec(
+        &mut deps,
+        mock_env_height(env.block.height, env.block.time.seconds() + 86400),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        Uint128::from(100u128),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropFinished {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
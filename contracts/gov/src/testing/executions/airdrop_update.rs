@@ -2,6 +2,7 @@ use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{attr, Api, Decimal, Env, MessageInfo, Uint128};
 use std::convert::TryFrom;
 
+use crate::error::ContractError;
 use crate::executions::airdrop::update;
 use crate::executions::ExecuteResult;
 use crate::states::airdrop::{Airdrop, Reward};
@@ -107,7 +108,8 @@ fn success() {
         response.attributes,
         vec![
             attr("action", "airdrop_update"),
-            attr("updated", "[0]".to_string())
+            attr("updated", "[0]".to_string()),
+            attr("pruned", "[0]".to_string()),
         ]
     );
 
@@ -222,7 +224,8 @@ fn success_with_third_party_sender() {
         response.attributes,
         vec![
             attr("action", "airdrop_update"),
-            attr("updated", "[0]".to_string())
+            attr("updated", "[0]".to_string()),
+            attr("pruned", "[0]".to_string()),
         ]
     );
 
@@ -322,7 +325,7 @@ fn success_with_multiple_shareholders() {
     );
     assert_eq!(airdrop.state.reward_per_token_stored, Decimal::one());
 
-    for voter in vec![TEST_VOTER, TEST_VOTER_2, TEST_VOTER_3].iter() {
+    for (i, voter) in [TEST_VOTER, TEST_VOTER_2, TEST_VOTER_3].iter().enumerate() {
         let reward =
             Reward::load(&deps.storage, &deps.api.addr_validate(voter).unwrap(), &0).unwrap();
         assert_eq!(reward.reward, Uint128::from(100u128));
@@ -338,13 +341,27 @@ fn success_with_multiple_shareholders() {
             Some(voter.to_string()),
         )
         .unwrap();
-        assert_eq!(
-            response.attributes,
-            vec![
-                attr("action", "airdrop_update"),
-                attr("updated", "[0]".to_string())
-            ]
-        );
+
+        // the first update prunes the finished airdrop,
+        // the others are settled without it being a candidate
+        if i == 0 {
+            assert_eq!(
+                response.attributes,
+                vec![
+                    attr("action", "airdrop_update"),
+                    attr("updated", "[0]".to_string()),
+                    attr("pruned", "[0]".to_string()),
+                ]
+            );
+        } else {
+            assert_eq!(
+                response.attributes,
+                vec![
+                    attr("action", "airdrop_update"),
+                    attr("updated", "[]".to_string())
+                ]
+            );
+        }
     }
 
     let airdrop = Airdrop::load(&deps.storage, &0).unwrap();
@@ -367,3 +384,90 @@ fn success_with_multiple_shareholders() {
         );
     }
 }
+
+#[test]
+fn success_without_share() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let (env, _, _) = super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+
+    let finished = mock_env_height(env.block.height, env.block.time.seconds() + 86400 * 2);
+    let response = exec(
+        &mut deps,
+        finished.clone(),
+        mock_info(TEST_VOTER, &[]),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "airdrop_update"),
+            attr("updated", "[0]".to_string()),
+            attr("pruned", "[0]".to_string()),
+        ]
+    );
+
+    // pruned airdrops are not settled for a staker without share
+    let voter = deps.api.addr_validate(TEST_VOTER).unwrap();
+    exec(
+        &mut deps,
+        finished.clone(),
+        mock_info(TEST_VOTER, &[]),
+        Some(TEST_VOTER.to_string()),
+    )
+    .unwrap();
+    assert_eq!(
+        Reward::load_range(&deps.storage, &voter, None, None, None).unwrap(),
+        vec![]
+    );
+
+    // nor after the first stake
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(100u128))],
+    )]);
+    let staked = mock_env_height(env.block.height, finished.block.time.seconds() + 100);
+    super::staking_deposit::exec(
+        &mut deps,
+        staked.clone(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        Uint128::from(100u128),
+    )
+    .unwrap();
+    assert_eq!(
+        Reward::load_last_settled(&deps.storage, &voter).unwrap(),
+        staked.block.time.seconds()
+    );
+
+    exec(
+        &mut deps,
+        staked,
+        mock_info(TEST_VOTER, &[]),
+        Some(TEST_VOTER.to_string()),
+    )
+    .unwrap();
+    assert_eq!(
+        Reward::load_range(&deps.storage, &voter, None, None, None).unwrap(),
+        vec![]
+    );
+}
+
+#[test]
+fn fail_invalid_target() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_VOTER, &[]),
+        Some("x".to_string()),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidAddress(address)) => assert_eq!(address, "x"),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -1,8 +1,17 @@
 pub mod airdrop_allocate;
+pub mod airdrop_allocate_batch;
+pub mod airdrop_cancel;
 pub mod airdrop_claim;
+pub mod airdrop_compact;
 pub mod airdrop_deallocate;
+pub mod airdrop_deallocate_batch;
+pub mod airdrop_extend;
 pub mod airdrop_instantiate;
+pub mod airdrop_top_up;
 pub mod airdrop_update;
+pub mod ownership_accept;
+pub mod ownership_cancel;
+pub mod ownership_propose;
 pub mod poll_cast_vote;
 pub mod poll_create;
 pub mod poll_end;
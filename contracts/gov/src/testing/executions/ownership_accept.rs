@@ -0,0 +1,102 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, from_binary, Env, MessageInfo};
+use pylon_token::gov_resp::ConfigResponse;
+
+use crate::error::ContractError;
+use crate::executions::{accept_ownership, ExecuteResult};
+use crate::queries::config::query_config;
+use crate::testing::{
+    instantiate, mock_deps, mock_env_height, MockDeps, TEST_CREATOR, TEST_VOTER, TEST_VOTER_2,
+};
+
+pub fn exec(deps: &mut MockDeps, env: Env, info: MessageInfo) -> ExecuteResult {
+    accept_ownership(deps.as_mut(), env, info)
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let (env, _, _) = super::ownership_propose::default(&mut deps, Some(100));
+
+    let response = exec(
+        &mut deps,
+        mock_env_height(env.block.height, env.block.time.seconds() + 100),
+        mock_info(TEST_VOTER, &[]),
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "accept_ownership"),
+            attr("owner", TEST_VOTER)
+        ]
+    );
+
+    let response = query_config(deps.as_ref()).unwrap();
+    let response: ConfigResponse = from_binary(&response).unwrap();
+    assert_eq!(response.owner, TEST_VOTER.to_string());
+    assert_eq!(response.pending_owner, None);
+}
+
+#[test]
+fn success_without_expiry() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let (env, _, _) = super::ownership_propose::default(&mut deps, None);
+
+    exec(
+        &mut deps,
+        mock_env_height(env.block.height, env.block.time.seconds() + 86400 * 365),
+        mock_info(TEST_VOTER, &[]),
+    )
+    .unwrap();
+
+    let response = query_config(deps.as_ref()).unwrap();
+    let response: ConfigResponse = from_binary(&response).unwrap();
+    assert_eq!(response.owner, TEST_VOTER.to_string());
+}
+
+#[test]
+fn fail_no_pending_owner() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(&mut deps, mock_env(), mock_info(TEST_VOTER, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NoPendingOwner {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::ownership_propose::default(&mut deps, None);
+
+    for sender in [TEST_CREATOR, TEST_VOTER_2] {
+        match exec(&mut deps, mock_env(), mock_info(sender, &[])) {
+            Ok(_) => panic!("Must return error"),
+            Err(ContractError::Unauthorized {}) => (),
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+}
+
+#[test]
+fn fail_expired() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let (env, _, _) = super::ownership_propose::default(&mut deps, Some(100));
+
+    match exec(
+        &mut deps,
+        mock_env_height(env.block.height, env.block.time.seconds() + 101),
+        mock_info(TEST_VOTER, &[]),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::OwnershipProposalExpired {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -0,0 +1,50 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, from_binary, Env, MessageInfo};
+use pylon_token::gov_resp::ConfigResponse;
+
+use crate::error::ContractError;
+use crate::executions::{cancel_ownership_proposal, ExecuteResult};
+use crate::queries::config::query_config;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_CREATOR, TEST_VOTER};
+
+pub fn exec(deps: &mut MockDeps, _env: Env, info: MessageInfo) -> ExecuteResult {
+    cancel_ownership_proposal(deps.as_mut(), info)
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::ownership_propose::default(&mut deps, None);
+
+    let response = exec(&mut deps, mock_env(), mock_info(TEST_CREATOR, &[])).unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![attr("action", "cancel_ownership_proposal")]
+    );
+
+    let response = query_config(deps.as_ref()).unwrap();
+    let response: ConfigResponse = from_binary(&response).unwrap();
+    assert_eq!(response.owner, TEST_CREATOR.to_string());
+    assert_eq!(response.pending_owner, None);
+
+    // cancelled proposal can not be accepted
+    match super::ownership_accept::exec(&mut deps, mock_env(), mock_info(TEST_VOTER, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NoPendingOwner {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    super::ownership_propose::default(&mut deps, None);
+
+    match exec(&mut deps, mock_env(), mock_info(TEST_VOTER, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -0,0 +1,81 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, from_binary, Env, MessageInfo, Response};
+use pylon_token::common::PendingOwnerResponse;
+use pylon_token::gov_resp::ConfigResponse;
+
+use crate::error::ContractError;
+use crate::executions::{propose_new_owner, ExecuteResult};
+use crate::queries::config::query_config;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_CREATOR, TEST_VOTER};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    expires_in: Option<u64>,
+) -> ExecuteResult {
+    propose_new_owner(deps.as_mut(), env, info, owner, expires_in)
+}
+
+pub fn default(deps: &mut MockDeps, expires_in: Option<u64>) -> (Env, MessageInfo, Response) {
+    let env = mock_env();
+    let info = mock_info(TEST_CREATOR, &[]);
+
+    let response = exec(
+        deps,
+        env.clone(),
+        info.clone(),
+        TEST_VOTER.to_string(),
+        expires_in,
+    )
+    .unwrap();
+
+    (env, info, response)
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let (env, _, response) = default(&mut deps, Some(100));
+    let expires_at = env.block.time.seconds() + 100;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "propose_new_owner"),
+            attr("pending_owner", TEST_VOTER),
+            attr("expires_at", expires_at.to_string()),
+        ]
+    );
+
+    let response = query_config(deps.as_ref()).unwrap();
+    let response: ConfigResponse = from_binary(&response).unwrap();
+    assert_eq!(response.owner, TEST_CREATOR.to_string());
+    assert_eq!(
+        response.pending_owner,
+        Some(PendingOwnerResponse {
+            owner: TEST_VOTER.to_string(),
+            expires_at: Some(expires_at),
+        })
+    );
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_VOTER, &[]),
+        TEST_VOTER.to_string(),
+        None,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -1,7 +1,7 @@
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{attr, from_binary, Env, MessageInfo, Response, Uint128};
 use pylon_token::common::OrderBy;
-use pylon_token::gov_msg::{VoteOption as GovVoteOption, VoterInfo as GovVoterInfo};
+use pylon_token::gov_msg::{PollTallyMode, VoteOption as GovVoteOption, VoterInfo as GovVoterInfo};
 use pylon_token::gov_resp::{PollResponse, StakerResponse, VotersResponse, VotersResponseItem};
 
 use crate::error::ContractError;
@@ -376,3 +376,81 @@ fn fail_poll_not_found() {
         Err(e) => panic!("Unexpected error: {:?}", e),
     }
 }
+
+#[test]
+fn fail_invalid_vote_option() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let default_proposal_deposit = instantiate::default_msg().proposal_deposit;
+
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(
+            &MOCK_CONTRACT_ADDR.to_string(),
+            &Uint128::from(11u128 + 3 * default_proposal_deposit.u128()),
+        )],
+    )]);
+
+    super::poll_create::default(&mut deps); // #1 - yes/no
+    super::poll_create::with_multiple_choice(&mut deps, PollTallyMode::Plurality); // #2
+    super::poll_create::with_multiple_choice(&mut deps, PollTallyMode::Approval); // #3
+    super::staking_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        Uint128::from(11u128),
+    )
+    .unwrap();
+
+    let cases: Vec<(u64, VoteOption)> = vec![
+        (1, VoteOption::Options(vec![0])),
+        (2, VoteOption::Yes),
+        (2, VoteOption::Options(vec![])),
+        (2, VoteOption::Options(vec![0, 1])),
+        (2, VoteOption::Options(vec![3])),
+        (3, VoteOption::No),
+        (3, VoteOption::Options(vec![1, 1])),
+        (3, VoteOption::Options(vec![0, 5])),
+    ];
+
+    for (poll_id, vote) in cases {
+        match exec(
+            &mut deps,
+            mock_env(),
+            mock_info(TEST_VOTER, &[]),
+            poll_id,
+            vote,
+            Uint128::from(5u128),
+        ) {
+            Ok(_) => panic!("Must return error"),
+            Err(ContractError::InvalidVoteOption {}) => (),
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    // approval vote counts the full amount for each option
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_VOTER, &[]),
+        3,
+        VoteOption::Options(vec![0, 2]),
+        Uint128::from(5u128),
+    )
+    .unwrap();
+
+    let response = query_poll(deps.as_ref(), 3).unwrap();
+    let response: PollResponse = from_binary(&response).unwrap();
+    let multiple_choice = response.multiple_choice.unwrap();
+    assert_eq!(multiple_choice.voted_amount, Uint128::from(5u128));
+    assert_eq!(
+        multiple_choice
+            .options
+            .iter()
+            .map(|option| option.votes)
+            .collect::<Vec<Uint128>>(),
+        vec![Uint128::from(5u128), Uint128::zero(), Uint128::from(5u128)]
+    );
+}
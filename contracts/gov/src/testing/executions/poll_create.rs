@@ -1,8 +1,9 @@
 use cosmwasm_std::testing::{mock_env, mock_info};
 use cosmwasm_std::{attr, to_binary, Deps, Env, MessageInfo, Response, StdError, Uint128};
 use cw20::Cw20ExecuteMsg;
-use pylon_token::gov_msg::PollExecuteMsg;
+use pylon_token::gov_msg::{PollExecuteMsg, PollMultipleChoiceMsg, PollOptionMsg, PollTallyMode};
 
+use crate::constant::MAX_POLL_OPTIONS;
 use crate::error::ContractError;
 use crate::executions::poll::create;
 use crate::executions::ExecuteResult;
@@ -20,6 +21,7 @@ pub struct Message {
     pub description: String,
     pub link: Option<String>,
     pub execute_msg: Option<Vec<PollExecuteMsg>>,
+    pub multiple_choice: Option<PollMultipleChoiceMsg>,
 }
 
 pub fn exec(deps: &mut MockDeps, env: Env, _info: MessageInfo, msg: Message) -> ExecuteResult {
@@ -33,6 +35,7 @@ pub fn exec(deps: &mut MockDeps, env: Env, _info: MessageInfo, msg: Message) ->
         msg.description,
         msg.link,
         msg.execute_msg,
+        msg.multiple_choice,
     )
 }
 
@@ -45,6 +48,35 @@ pub fn default(deps: &mut MockDeps) -> (Env, MessageInfo, Response) {
     (env, info, response)
 }
 
+pub fn with_execute_msgs(deps: &mut MockDeps) -> (Env, MessageInfo, Response) {
+    let env = mock_env();
+    let info = mock_info(TEST_CREATOR, &[]);
+
+    let msg = Message {
+        execute_msg: Some(default_exec_msgs()),
+        ..default_msg()
+    };
+    let response = exec(deps, env.clone(), info.clone(), msg).unwrap();
+
+    (env, info, response)
+}
+
+pub fn with_multiple_choice(
+    deps: &mut MockDeps,
+    mode: PollTallyMode,
+) -> (Env, MessageInfo, Response) {
+    let env = mock_env();
+    let info = mock_info(TEST_CREATOR, &[]);
+
+    let msg = Message {
+        multiple_choice: Some(multiple_choice_msg(mode)),
+        ..default_msg()
+    };
+    let response = exec(deps, env.clone(), info.clone(), msg).unwrap();
+
+    (env, info, response)
+}
+
 pub fn default_msg() -> Message {
     Message {
         proposer: TEST_CREATOR.to_string(),
@@ -54,10 +86,10 @@ pub fn default_msg() -> Message {
         description: "test".to_string(),
         link: None,
         execute_msg: None,
+        multiple_choice: None,
     }
 }
 
-#[allow(dead_code)]
 pub fn default_exec_msgs() -> Vec<PollExecuteMsg> {
     let exec_msg_bz = to_binary(&Cw20ExecuteMsg::Burn {
         amount: Uint128::new(123),
@@ -93,6 +125,36 @@ pub fn default_exec_msgs() -> Vec<PollExecuteMsg> {
     ]
 }
 
+/// three options - #0 burns 1 token, #1 burns 2 tokens and #2 is a signal option
+pub fn multiple_choice_msg(mode: PollTallyMode) -> PollMultipleChoiceMsg {
+    let burn_msg = |amount: u128| PollExecuteMsg {
+        order: 1u64,
+        contract: VOTING_TOKEN.to_string(),
+        msg: to_binary(&Cw20ExecuteMsg::Burn {
+            amount: Uint128::new(amount),
+        })
+        .unwrap(),
+    };
+
+    PollMultipleChoiceMsg {
+        mode,
+        options: vec![
+            PollOptionMsg {
+                name: "burn-1".to_string(),
+                execute_msgs: Some(vec![burn_msg(1)]),
+            },
+            PollOptionMsg {
+                name: "burn-2".to_string(),
+                execute_msgs: Some(vec![burn_msg(2)]),
+            },
+            PollOptionMsg {
+                name: "keep".to_string(),
+                execute_msgs: None,
+            },
+        ],
+    }
+}
+
 // helper to confirm the expected create_poll response
 pub fn assert_create_poll_result(
     poll_id: u64,
@@ -120,7 +182,8 @@ pub fn assert_create_poll_result(
             total_share: Uint128::zero(),
             total_deposit: instantiate::default_msg().proposal_deposit,
             total_airdrop_count: 0,
-            airdrop_update_candidates: vec![]
+            airdrop_update_candidates: vec![],
+            airdrop_fund: Uint128::zero(),
         }
     );
 }
@@ -234,3 +297,71 @@ fn fail_invalid_deposit() {
         Err(_) => panic!("Unknown error"),
     }
 }
+
+#[test]
+fn fail_invalid_multiple_choice() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let option = |name: &str| PollOptionMsg {
+        name: name.to_string(),
+        execute_msgs: None,
+    };
+    let cases: Vec<(Message, ContractError)> = vec![
+        (
+            Message {
+                multiple_choice: Some(PollMultipleChoiceMsg {
+                    mode: PollTallyMode::Plurality,
+                    options: vec![option("only")],
+                }),
+                ..default_msg()
+            },
+            ContractError::TooFewPollOptions {},
+        ),
+        (
+            Message {
+                multiple_choice: Some(PollMultipleChoiceMsg {
+                    mode: PollTallyMode::Plurality,
+                    options: vec![option("option"); MAX_POLL_OPTIONS + 1],
+                }),
+                ..default_msg()
+            },
+            ContractError::TooManyPollOptions {},
+        ),
+        (
+            Message {
+                multiple_choice: Some(PollMultipleChoiceMsg {
+                    mode: PollTallyMode::Approval,
+                    options: vec![option("option"), option("")],
+                }),
+                ..default_msg()
+            },
+            ContractError::PollOptionNameTooShort {},
+        ),
+        (
+            Message {
+                multiple_choice: Some(PollMultipleChoiceMsg {
+                    mode: PollTallyMode::Approval,
+                    options: vec![option("option"), option(LONG_STRING)],
+                }),
+                ..default_msg()
+            },
+            ContractError::PollOptionNameTooLong {},
+        ),
+        (
+            Message {
+                execute_msg: Some(default_exec_msgs()),
+                multiple_choice: Some(multiple_choice_msg(PollTallyMode::Plurality)),
+                ..default_msg()
+            },
+            ContractError::ExecuteDataPerOption {},
+        ),
+    ];
+
+    for (msg, expected) in cases {
+        match exec(&mut deps, mock_env(), mock_info(VOTING_TOKEN, &[]), msg) {
+            Ok(_) => panic!("Must return error"),
+            Err(e) => assert_eq!(e, expected),
+        }
+    }
+}
@@ -5,6 +5,7 @@ use cosmwasm_std::{
 };
 use cw20::Cw20ExecuteMsg;
 use pylon_token::common::OrderBy;
+use pylon_token::gov_msg::PollTallyMode;
 use pylon_token::gov_resp::{PollResponse, PollsResponse, StakerResponse, VotersResponse};
 use terraswap::querier::query_token_balance;
 
@@ -17,7 +18,7 @@ use crate::states::bank::TokenManager;
 use crate::states::poll::{PollStatus, VoteOption, VoterInfo};
 use crate::testing::{
     instantiate, mock_deps, mock_env_height, MockDeps, TEST_CREATOR, TEST_VOTER, TEST_VOTER_2,
-    VOTING_TOKEN,
+    TEST_VOTER_3, VOTING_TOKEN,
 };
 
 pub fn exec(deps: &mut MockDeps, env: Env, _info: MessageInfo, poll_id: u64) -> ExecuteResult {
@@ -171,7 +172,7 @@ fn end_poll() {
     // create poll
     const POLL_ID: u64 = 1;
     const STAKE_AMOUNT: u128 = 1000;
-    let (env, _, _) = super::poll_create::default(&mut deps); // #1
+    let (env, _, _) = super::poll_create::with_execute_msgs(&mut deps); // #1
 
     let proposal_deposit = default_init_msg.proposal_deposit.u128();
     let end_height = env.block.height + default_init_msg.voting_period;
@@ -262,7 +263,7 @@ fn end_poll_with_controlled_quorum() {
     // create poll
     const STAKE_AMOUNT: u128 = 1000;
     const POLL_ID: u64 = 1;
-    let (env, _, _) = super::poll_create::default(&mut deps);
+    let (env, _, _) = super::poll_create::with_execute_msgs(&mut deps);
 
     let proposal_deposit = default_init_msg.proposal_deposit.u128();
     let end_height = env.block.height + default_init_msg.voting_period;
@@ -482,6 +483,288 @@ fn end_poll_nay_rejected() {
     assert_end_poll_fail(&deps, response, POLL_ID, "Threshold not reached");
 }
 
+#[test]
+fn end_signal_poll() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let default_init_msg = instantiate::default_msg();
+
+    // create poll without execute data
+    const POLL_ID: u64 = 1;
+    const STAKE_AMOUNT: u128 = 1000;
+    let (env, _, _) = super::poll_create::default(&mut deps);
+
+    let proposal_deposit = default_init_msg.proposal_deposit.u128();
+    let end_height = env.block.height + default_init_msg.voting_period;
+
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(
+            &MOCK_CONTRACT_ADDR.to_string(),
+            &Uint128::from(STAKE_AMOUNT + proposal_deposit),
+        )],
+    )]);
+
+    super::poll_cast_vote::with_stake(
+        &mut deps,
+        POLL_ID,
+        TEST_VOTER.to_string(),
+        VoteOption::Yes,
+        STAKE_AMOUNT,
+    );
+
+    let response = exec(
+        &mut deps,
+        mock_env_height(end_height, 0),
+        mock_info(TEST_CREATOR, &[]),
+        POLL_ID,
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "end_poll"),
+            attr("poll_id", POLL_ID.to_string()),
+            attr("rejected_reason", ""),
+            attr("passed", "true"),
+        ]
+    );
+
+    let response = query_poll(deps.as_ref(), POLL_ID).unwrap();
+    let response: PollResponse = from_binary(&response).unwrap();
+    assert_eq!(response.status, PollStatus::Concluded.into());
+
+    // must not be picked up by executors
+    let response = query_polls_with_status_filter(
+        deps.as_ref(),
+        Some(PollStatus::Passed),
+        None,
+        None,
+        Some(OrderBy::Desc),
+    )
+    .unwrap();
+    let response: PollsResponse = from_binary(&response).unwrap();
+    assert_eq!(response.polls.len(), 0);
+
+    let response = query_polls_with_status_filter(
+        deps.as_ref(),
+        Some(PollStatus::Concluded),
+        None,
+        None,
+        Some(OrderBy::Desc),
+    )
+    .unwrap();
+    let response: PollsResponse = from_binary(&response).unwrap();
+    assert_eq!(response.polls.len(), 1);
+
+    match super::poll_execute::exec(
+        &mut deps,
+        mock_env_height(end_height + default_init_msg.timelock_period, 0),
+        mock_info(TEST_CREATOR, &[]),
+        POLL_ID,
+    ) {
+        Err(ContractError::PollNotPassed {}) => (),
+        _ => panic!("Must return poll not passed error"),
+    }
+}
+
+/// creates a multiple-choice poll #1 and casts the votes, returns the end height
+pub fn setup_multiple_choice(
+    deps: &mut MockDeps,
+    mode: PollTallyMode,
+    votes: Vec<(&str, Vec<u32>, u128)>,
+) -> u64 {
+    let default_init_msg = instantiate::default_msg();
+    let (env, _, _) = super::poll_create::with_multiple_choice(deps, mode);
+
+    let mut staked = default_init_msg.proposal_deposit.u128();
+    for (voter, options, amount) in votes {
+        staked += amount;
+        deps.querier.with_token_balances(&[(
+            &VOTING_TOKEN.to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(staked))],
+        )]);
+
+        super::poll_cast_vote::with_stake(
+            deps,
+            1,
+            voter.to_string(),
+            VoteOption::Options(options),
+            amount,
+        );
+    }
+
+    env.block.height + default_init_msg.voting_period
+}
+
+#[test]
+fn end_multiple_choice_poll_plurality() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    // option #1 leads with 700 / 1200 (> 50% threshold)
+    const POLL_ID: u64 = 1;
+    let end_height = setup_multiple_choice(
+        &mut deps,
+        PollTallyMode::Plurality,
+        vec![
+            (TEST_VOTER, vec![0], 300),
+            (TEST_VOTER_2, vec![1], 700),
+            (TEST_VOTER_3, vec![2], 200),
+        ],
+    );
+
+    let (_, _, response) = default(&mut deps, end_height, POLL_ID);
+    assert_end_poll_success(
+        &deps,
+        response,
+        POLL_ID,
+        instantiate::default_msg().proposal_deposit,
+    );
+
+    let response = query_poll(deps.as_ref(), POLL_ID).unwrap();
+    let response: PollResponse = from_binary(&response).unwrap();
+    assert_eq!(response.multiple_choice.unwrap().winning_option, Some(1));
+}
+
+#[test]
+fn end_multiple_choice_poll_plurality_threshold_rejected() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    // option #1 leads, but with 500 / 1200 only
+    const POLL_ID: u64 = 1;
+    let end_height = setup_multiple_choice(
+        &mut deps,
+        PollTallyMode::Plurality,
+        vec![
+            (TEST_VOTER, vec![0], 400),
+            (TEST_VOTER_2, vec![1], 500),
+            (TEST_VOTER_3, vec![2], 300),
+        ],
+    );
+
+    let response = exec(
+        &mut deps,
+        mock_env_height(end_height, 0),
+        mock_info(TEST_CREATOR, &[]),
+        POLL_ID,
+    )
+    .unwrap();
+    assert_end_poll_fail(&deps, response, POLL_ID, "Threshold not reached");
+}
+
+#[test]
+fn end_multiple_choice_poll_signal_option() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    const POLL_ID: u64 = 1;
+    let end_height = setup_multiple_choice(
+        &mut deps,
+        PollTallyMode::Plurality,
+        vec![(TEST_VOTER, vec![0], 400), (TEST_VOTER_2, vec![2], 500)],
+    );
+
+    // option #2 has nothing to execute
+    exec(
+        &mut deps,
+        mock_env_height(end_height, 0),
+        mock_info(TEST_CREATOR, &[]),
+        POLL_ID,
+    )
+    .unwrap();
+
+    let response = query_poll(deps.as_ref(), POLL_ID).unwrap();
+    let response: PollResponse = from_binary(&response).unwrap();
+    assert_eq!(response.status, PollStatus::Concluded.into());
+    assert_eq!(response.multiple_choice.unwrap().winning_option, Some(2));
+}
+
+#[test]
+fn end_multiple_choice_poll_tie_rejected() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    const POLL_ID: u64 = 1;
+    let end_height = setup_multiple_choice(
+        &mut deps,
+        PollTallyMode::Plurality,
+        vec![(TEST_VOTER, vec![0], 500), (TEST_VOTER_2, vec![1], 500)],
+    );
+
+    let response = exec(
+        &mut deps,
+        mock_env_height(end_height, 0),
+        mock_info(TEST_CREATOR, &[]),
+        POLL_ID,
+    )
+    .unwrap();
+    assert_end_poll_fail(&deps, response, POLL_ID, "No winning option");
+
+    let response = query_poll(deps.as_ref(), POLL_ID).unwrap();
+    let response: PollResponse = from_binary(&response).unwrap();
+    assert_eq!(response.multiple_choice.unwrap().winning_option, None);
+}
+
+#[test]
+fn end_multiple_choice_poll_approval() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    // option #0 approved by 900 / 1200 (> 50% threshold)
+    const POLL_ID: u64 = 1;
+    let end_height = setup_multiple_choice(
+        &mut deps,
+        PollTallyMode::Approval,
+        vec![
+            (TEST_VOTER, vec![0, 1], 400),
+            (TEST_VOTER_2, vec![0, 2], 500),
+            (TEST_VOTER_3, vec![1], 300),
+        ],
+    );
+
+    let (_, _, response) = default(&mut deps, end_height, POLL_ID);
+    assert_end_poll_success(
+        &deps,
+        response,
+        POLL_ID,
+        instantiate::default_msg().proposal_deposit,
+    );
+
+    let response = query_poll(deps.as_ref(), POLL_ID).unwrap();
+    let response: PollResponse = from_binary(&response).unwrap();
+    assert_eq!(response.multiple_choice.unwrap().winning_option, Some(0));
+}
+
+#[test]
+fn end_multiple_choice_poll_approval_threshold_rejected() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    // option #1 leads, but is approved by 500 / 1200 only
+    const POLL_ID: u64 = 1;
+    let end_height = setup_multiple_choice(
+        &mut deps,
+        PollTallyMode::Approval,
+        vec![
+            (TEST_VOTER, vec![0], 400),
+            (TEST_VOTER_2, vec![1], 500),
+            (TEST_VOTER_3, vec![2], 300),
+        ],
+    );
+
+    let response = exec(
+        &mut deps,
+        mock_env_height(end_height, 0),
+        mock_info(TEST_CREATOR, &[]),
+        POLL_ID,
+    )
+    .unwrap();
+    assert_end_poll_fail(&deps, response, POLL_ID, "Threshold not reached");
+}
+
 #[test]
 fn fails_end_poll_before_end_height() {
     let mut deps = mock_deps();
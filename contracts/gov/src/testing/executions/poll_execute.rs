@@ -1,10 +1,12 @@
-use cosmwasm_std::testing::MOCK_CONTRACT_ADDR;
-use cosmwasm_std::{Env, MessageInfo, Uint128};
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{to_binary, CosmosMsg, Env, MessageInfo, SubMsg, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+use pylon_token::gov_msg::PollTallyMode;
 
-use crate::executions::poll::execute;
+use crate::executions::poll::{execute, execute_messages};
 use crate::executions::ExecuteResult;
 use crate::states::poll::VoteOption;
-use crate::testing::{instantiate, mock_deps, MockDeps, TEST_VOTER, VOTING_TOKEN};
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_VOTER, TEST_VOTER_2, VOTING_TOKEN};
 
 #[allow(dead_code)]
 pub fn exec(deps: &mut MockDeps, env: Env, _info: MessageInfo, poll_id: u64) -> ExecuteResult {
@@ -45,6 +47,39 @@ fn success() {
     super::poll_end::default(&mut deps, end_height, POLL_ID);
 }
 
+#[test]
+fn execute_winning_option_messages() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    const POLL_ID: u64 = 1;
+    let end_height = super::poll_end::setup_multiple_choice(
+        &mut deps,
+        PollTallyMode::Plurality,
+        vec![(TEST_VOTER, vec![0], 400), (TEST_VOTER_2, vec![1], 500)],
+    );
+    super::poll_end::default(&mut deps, end_height, POLL_ID);
+
+    let response = execute_messages(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        POLL_ID,
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: VOTING_TOKEN.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Burn {
+                amount: Uint128::new(2),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+}
+
 // use crate::entrypoints;
 // use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 // use cosmwasm_std::{
@@ -1,11 +1,15 @@
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-use cosmwasm_std::{attr, Deps, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{
+    attr, to_binary, CosmosMsg, Deps, Env, MessageInfo, Response, SubMsg, Uint128, WasmMsg,
+};
+use cw20::Cw20ReceiveMsg;
+use pylon_token::gov_msg::{AirdropMsg, Cw20HookMsg, ExecuteMsg, StakingMsg};
 
 use crate::error::ContractError;
 use crate::executions::staking::stake_voting_tokens;
-use crate::executions::ExecuteResult;
+use crate::executions::{receive, ExecuteResult};
 use crate::states::state::State;
-use crate::testing::{instantiate, mock_deps, MockDeps, TEST_VOTER, VOTING_TOKEN};
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_CREATOR, TEST_VOTER, VOTING_TOKEN};
 
 pub fn exec(
     deps: &mut MockDeps,
@@ -38,7 +42,8 @@ pub fn assert_stake_tokens_result(
             total_share: Uint128::from(total_share),
             total_deposit: Uint128::from(total_deposit),
             total_airdrop_count: 0,
-            airdrop_update_candidates: vec![]
+            airdrop_update_candidates: vec![],
+            airdrop_fund: Uint128::zero(),
         }
     );
 }
@@ -104,3 +109,64 @@ fn fail_insufficient_funds() {
         Err(e) => panic!("Unexpected error: {:?}", e),
     }
 }
+
+#[test]
+fn receive_stake_on_behalf() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let response = receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(VOTING_TOKEN, &[]),
+        Cw20ReceiveMsg {
+            sender: TEST_CREATOR.to_string(),
+            amount: Uint128::from(11u128),
+            msg: to_binary(&Cw20HookMsg::Stake {
+                staker: Some(TEST_VOTER.to_string()),
+            })
+            .unwrap(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: MOCK_CONTRACT_ADDR.to_string(),
+                msg: to_binary(&ExecuteMsg::Airdrop(AirdropMsg::Update {
+                    target: Some(TEST_VOTER.to_string()),
+                }))
+                .unwrap(),
+                funds: vec![],
+            })),
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: MOCK_CONTRACT_ADDR.to_string(),
+                msg: to_binary(&ExecuteMsg::Staking(StakingMsg::StakeInternal {
+                    sender: TEST_VOTER.to_string(),
+                    amount: Uint128::from(11u128),
+                }))
+                .unwrap(),
+                funds: vec![],
+            })),
+        ]
+    );
+
+    match receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(VOTING_TOKEN, &[]),
+        Cw20ReceiveMsg {
+            sender: TEST_CREATOR.to_string(),
+            amount: Uint128::from(11u128),
+            msg: to_binary(&Cw20HookMsg::Stake {
+                staker: Some("x".to_string()),
+            })
+            .unwrap(),
+        },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidAddress(_)) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -1,5 +1,7 @@
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-use cosmwasm_std::{from_binary, to_binary, CosmosMsg, Env, MessageInfo, SubMsg, Uint128, WasmMsg};
+use cosmwasm_std::{
+    from_binary, to_binary, Api, CosmosMsg, Env, MessageInfo, SubMsg, Uint128, WasmMsg,
+};
 use cw20::Cw20ExecuteMsg;
 use pylon_token::gov_msg::{VoteOption as GovVoteOption, VoterInfo as GovVoterInfo};
 use pylon_token::gov_resp::StakerResponse;
@@ -8,7 +10,8 @@ use crate::error::ContractError;
 use crate::executions::staking::withdraw_voting_tokens;
 use crate::executions::ExecuteResult;
 use crate::queries::bank::query_staker;
-use crate::states::poll::{Poll, PollStatus, VoteOption};
+use crate::states::bank::TokenManager;
+use crate::states::poll::{Poll, PollStatus, VoteOption, VoterInfo};
 use crate::testing::{instantiate, mock_deps, MockDeps, TEST_VOTER, VOTING_TOKEN};
 
 pub fn exec(
@@ -236,3 +239,46 @@ fn test_invalid_withdraw_amount() {
         Err(e) => panic!("Unexpected error: {:?}", e),
     }
 }
+
+#[test]
+fn fail_locked_in_unknown_poll() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(11u128))],
+    )]);
+
+    super::staking_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        Uint128::from(11u128),
+    )
+    .unwrap();
+
+    let voter = deps.api.addr_canonicalize(TEST_VOTER).unwrap();
+    let mut token_manager = TokenManager::load(&deps.storage, &voter).unwrap();
+    token_manager.locked_balance.push((
+        99,
+        VoterInfo {
+            vote: VoteOption::Yes,
+            balance: Uint128::from(1u128),
+        },
+    ));
+    TokenManager::save(&mut deps.storage, &voter, &token_manager).unwrap();
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        None,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::PollNotFound {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -1,5 +1,5 @@
 use cosmwasm_std::testing::{mock_env, mock_info};
-use cosmwasm_std::{from_binary, Decimal, Env, MessageInfo, Uint128};
+use cosmwasm_std::{attr, from_binary, Decimal, Env, MessageInfo, Uint128};
 
 use pylon_token::gov_resp::ConfigResponse;
 
@@ -10,7 +10,6 @@ use crate::testing::{instantiate, mock_deps, MockDeps, TEST_CREATOR, TEST_VOTER}
 
 #[derive(Clone)]
 pub struct Message {
-    pub owner: Option<String>,
     pub quorum: Option<Decimal>,
     pub threshold: Option<Decimal>,
     pub voting_period: Option<u64>,
@@ -19,11 +18,11 @@ pub struct Message {
     pub snapshot_period: Option<u64>,
 }
 
-pub fn exec(deps: &mut MockDeps, _env: Env, info: MessageInfo, msg: Message) -> ExecuteResult {
+pub fn exec(deps: &mut MockDeps, env: Env, info: MessageInfo, msg: Message) -> ExecuteResult {
     update_config(
         deps.as_mut(),
+        env,
         info,
-        msg.owner,
         msg.quorum,
         msg.threshold,
         msg.voting_period,
@@ -35,7 +34,6 @@ pub fn exec(deps: &mut MockDeps, _env: Env, info: MessageInfo, msg: Message) ->
 
 pub fn default_msg() -> Message {
     Message {
-        owner: None,
         quorum: None,
         threshold: None,
         voting_period: None,
@@ -50,16 +48,6 @@ fn success() {
     let mut deps = mock_deps();
     instantiate::default(&mut deps);
 
-    // try update owner
-    let mut msg = default_msg();
-    msg.owner = Some(TEST_VOTER.to_string());
-    exec(&mut deps, mock_env(), mock_info(TEST_CREATOR, &[]), msg).unwrap();
-
-    let response = query_config(deps.as_ref()).unwrap();
-    let response: ConfigResponse = from_binary(&response).unwrap();
-    assert_eq!(response.owner, TEST_VOTER.to_string());
-
-    // try update others
     let mut msg = default_msg();
     msg.quorum = Some(Decimal::percent(20));
     msg.threshold = Some(Decimal::percent(75));
@@ -67,13 +55,30 @@ fn success() {
     msg.timelock_period = Some(20000u64);
     msg.proposal_deposit = Some(Uint128::from(123u128));
     msg.snapshot_period = Some(11);
-    exec(
+    let response = exec(
         &mut deps,
         mock_env(),
-        mock_info(TEST_VOTER, &[]),
+        mock_info(TEST_CREATOR, &[]),
         msg.clone(),
     )
     .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_config"),
+            attr("old_quorum", "0.3"),
+            attr("new_quorum", "0.2"),
+            attr("old_threshold", "0.5"),
+            attr("new_threshold", "0.75"),
+            attr("old_timelock_period", "10000"),
+            attr("new_timelock_period", "20000"),
+            attr("old_proposal_deposit", "10000000000"),
+            attr("new_proposal_deposit", "123"),
+            attr("old_snapshot_period", "10"),
+            attr("new_snapshot_period", "11"),
+            attr("config_change_id", "0"),
+        ]
+    );
 
     let response = query_config(deps.as_ref()).unwrap();
     let response: ConfigResponse = from_binary(&response).unwrap();
@@ -100,3 +105,81 @@ fn fail_unauthorized() {
         _ => panic!("Must return unauthorized error"),
     }
 }
+
+#[test]
+fn fail_invalid_config() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let cases: Vec<(Message, ContractError)> = vec![
+        (
+            Message {
+                quorum: Some(Decimal::percent(101)),
+                ..default_msg()
+            },
+            ContractError::InvalidQuorum {},
+        ),
+        (
+            Message {
+                threshold: Some(Decimal::percent(101)),
+                ..default_msg()
+            },
+            ContractError::InvalidThreshold {},
+        ),
+        (
+            Message {
+                voting_period: Some(0),
+                snapshot_period: Some(0),
+                ..default_msg()
+            },
+            ContractError::InvalidVotingPeriod {},
+        ),
+        (
+            Message {
+                timelock_period: Some(0),
+                ..default_msg()
+            },
+            ContractError::InvalidTimelockPeriod {},
+        ),
+        (
+            Message {
+                snapshot_period: Some(instantiate::default_msg().voting_period),
+                ..default_msg()
+            },
+            ContractError::InvalidSnapshotPeriod {},
+        ),
+        (
+            // voting period is validated against the current snapshot period
+            Message {
+                voting_period: Some(instantiate::default_msg().snapshot_period),
+                ..default_msg()
+            },
+            ContractError::InvalidSnapshotPeriod {},
+        ),
+        (
+            Message {
+                proposal_deposit: Some(Uint128::zero()),
+                ..default_msg()
+            },
+            ContractError::InvalidProposalDeposit {},
+        ),
+    ];
+
+    for (msg, expected) in cases {
+        match exec(&mut deps, mock_env(), mock_info(TEST_CREATOR, &[]), msg) {
+            Ok(_) => panic!("Must return error"),
+            Err(e) => assert_eq!(e, expected),
+        }
+    }
+
+    // nothing changed
+    let response = query_config(deps.as_ref()).unwrap();
+    let response: ConfigResponse = from_binary(&response).unwrap();
+    let default_msg = instantiate::default_msg();
+    assert_eq!(response.quorum, default_msg.quorum);
+    assert_eq!(response.threshold, default_msg.threshold);
+    assert_eq!(response.voting_period, default_msg.voting_period);
+    assert_eq!(response.timelock_period, default_msg.timelock_period);
+    assert_eq!(response.proposal_deposit, default_msg.proposal_deposit);
+    assert_eq!(response.snapshot_period, default_msg.snapshot_period);
+}
@@ -1,5 +1,5 @@
 use cosmwasm_std::testing::{mock_env, mock_info};
-use cosmwasm_std::{Api, Decimal, Env, MessageInfo, Response, StdError, Uint128};
+use cosmwasm_std::{Api, Decimal, Env, MessageInfo, Response, Uint128};
 use pylon_token::gov_msg::InstantiateMsg;
 
 use crate::error::ContractError;
@@ -52,6 +52,7 @@ fn success() {
         Config {
             pylon_token: deps.api.addr_canonicalize(VOTING_TOKEN).unwrap(),
             owner: deps.api.addr_canonicalize(TEST_CREATOR).unwrap(),
+            pending_owner: None,
             quorum: default_msg.quorum,
             threshold: default_msg.threshold,
             voting_period: default_msg.voting_period,
@@ -70,7 +71,8 @@ fn success() {
             total_share: Uint128::zero(),
             total_deposit: Uint128::zero(),
             total_airdrop_count: 0,
-            airdrop_update_candidates: vec![]
+            airdrop_update_candidates: vec![],
+            airdrop_fund: Uint128::zero(),
         }
     );
 }
@@ -83,9 +85,7 @@ fn fail_invalid_quorum() {
 
     match exec(&mut deps, mock_env(), mock_info(TEST_CREATOR, &[]), msg) {
         Ok(_) => panic!("Must return error"),
-        Err(ContractError::Std(StdError::GenericErr { msg, .. })) => {
-            assert_eq!(msg, "quorum must be 0 to 1")
-        }
+        Err(ContractError::InvalidQuorum {}) => (),
         Err(e) => panic!("Unexpected error: {:?}", e),
     }
 }
@@ -98,9 +98,20 @@ fn fail_invalid_threshold() {
 
     match exec(&mut deps, mock_env(), mock_info(TEST_CREATOR, &[]), msg) {
         Ok(_) => panic!("Must return error"),
-        Err(ContractError::Std(StdError::GenericErr { msg, .. })) => {
-            assert_eq!(msg, "threshold must be 0 to 1")
-        }
+        Err(ContractError::InvalidThreshold {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_invalid_periods() {
+    let mut deps = mock_deps();
+    let mut msg = default_msg();
+    msg.snapshot_period = msg.voting_period;
+
+    match exec(&mut deps, mock_env(), mock_info(TEST_CREATOR, &[]), msg) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidSnapshotPeriod {}) => (),
         Err(e) => panic!("Unexpected error: {:?}", e),
     }
 }
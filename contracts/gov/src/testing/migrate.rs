@@ -0,0 +1,135 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{from_binary, Addr, Decimal, StdError, Uint128};
+use cosmwasm_storage::Bucket;
+use pylon_token::common::OrderBy;
+use pylon_token::gov_resp::PollsResponse;
+use terraswap::asset::AssetInfo;
+
+use crate::error::ContractError;
+use crate::migrations::state::migrate;
+use crate::queries::poll::query_polls_with_status_filter;
+use crate::states::airdrop::{self, Airdrop};
+use crate::states::poll::{ExecuteData, Poll, PollStatus};
+use crate::states::PREFIX_AIRDROP;
+use crate::testing::executions::poll_create;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_TOKEN};
+
+fn query_poll_ids(deps: &MockDeps, status: PollStatus) -> Vec<u64> {
+    let response =
+        query_polls_with_status_filter(deps.as_ref(), Some(status), None, None, Some(OrderBy::Asc))
+            .unwrap();
+    let response: PollsResponse = from_binary(&response).unwrap();
+    response.polls.iter().map(|poll| poll.id).collect()
+}
+
+#[test]
+fn conclude_passed_signal_polls() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    poll_create::default(&mut deps); // #1 - signal poll
+    poll_create::with_execute_msgs(&mut deps); // #2
+    poll_create::default(&mut deps); // #3 - signal poll, but rejected
+
+    for (poll_id, status) in [
+        (1u64, PollStatus::Passed),
+        (2u64, PollStatus::Passed),
+        (3u64, PollStatus::Rejected),
+    ] {
+        let mut poll = Poll::load(deps.as_ref().storage, &poll_id).unwrap();
+        Poll::deindex_status(deps.as_mut().storage, &poll_id, &poll.status);
+        poll.status = status.clone();
+        if poll_id == 1 {
+            // also covers polls created with an empty list of messages
+            poll.execute_data = Some(Vec::<ExecuteData>::new());
+        }
+        Poll::save(deps.as_mut().storage, &poll_id, &poll).unwrap();
+        Poll::index_status(deps.as_mut().storage, &poll_id, &status).unwrap();
+    }
+
+    migrate(deps.as_mut(), mock_env()).unwrap();
+
+    assert_eq!(query_poll_ids(&deps, PollStatus::Passed), vec![2]);
+    assert_eq!(query_poll_ids(&deps, PollStatus::Concluded), vec![1]);
+    assert_eq!(query_poll_ids(&deps, PollStatus::Rejected), vec![3]);
+    assert_eq!(
+        Poll::load(deps.as_ref().storage, &1).unwrap().status,
+        PollStatus::Concluded
+    );
+}
+
+#[test]
+fn convert_legacy_airdrop_reward_token() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct LegacyAirdropConfig {
+        start: u64,
+        period: u64,
+        reward_token: Addr,
+        reward_rate: Decimal,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct LegacyAirdrop {
+        config: LegacyAirdropConfig,
+        state: airdrop::State,
+    }
+
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let state = airdrop::State {
+        last_update_time: 100,
+        reward_per_token_stored: Decimal::zero(),
+        funded: Uint128::zero(),
+        distributed: Uint128::zero(),
+    };
+    Bucket::new(deps.as_mut().storage, PREFIX_AIRDROP)
+        .save(
+            &0u64.to_be_bytes(),
+            &LegacyAirdrop {
+                config: LegacyAirdropConfig {
+                    start: 100,
+                    period: 200,
+                    reward_token: Addr::unchecked(TEST_TOKEN),
+                    reward_rate: Decimal::percent(500),
+                },
+                state: state.clone(),
+            },
+        )
+        .unwrap();
+
+    migrate(deps.as_mut(), mock_env()).unwrap();
+    // must be idempotent
+    migrate(deps.as_mut(), mock_env()).unwrap();
+
+    assert_eq!(
+        Airdrop::load(deps.as_ref().storage, &0).unwrap(),
+        Airdrop {
+            config: airdrop::Config {
+                start: 100,
+                period: 200,
+                reward_token: AssetInfo::Token {
+                    contract_addr: TEST_TOKEN.to_string(),
+                },
+                reward_rate: Decimal::percent(500),
+            },
+            state,
+        }
+    );
+}
+
+#[test]
+fn fail_malformed_airdrop() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    Bucket::new(deps.as_mut().storage, PREFIX_AIRDROP)
+        .save(&0u64.to_be_bytes(), &Uint128::from(100u128))
+        .unwrap();
+
+    match migrate(deps.as_mut(), mock_env()) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Std(StdError::ParseErr { .. })) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
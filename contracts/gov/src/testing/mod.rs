@@ -5,6 +5,7 @@ use crate::testing::mock_querier::{mock_dependencies, WasmMockQuerier};
 
 mod executions;
 mod instantiate;
+mod migrate;
 mod mock_querier;
 mod queries;
 
@@ -0,0 +1,185 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{from_binary, Uint128};
+use pylon_token::common::OrderBy;
+use pylon_token::gov_resp::{
+    AirdropAllocationsResponse, AirdropAllocationsResponseItem, AirdropProjectionResponse,
+};
+
+use crate::error::ContractError;
+use crate::queries::airdrop::{query_airdrop, query_airdrop_allocations, query_airdrop_projection};
+use crate::testing::executions::{
+    airdrop_allocate_batch, airdrop_deallocate_batch, airdrop_instantiate, airdrop_update,
+    staking_deposit,
+};
+use crate::testing::{
+    instantiate, mock_deps, mock_env_height, TEST_CREATOR, TEST_TOKEN, TEST_VOTER, TEST_VOTER_2,
+    TEST_VOTER_3, VOTING_TOKEN,
+};
+
+#[test]
+fn airdrop_allocations() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    airdrop_instantiate::default(&mut deps, TEST_TOKEN, 1000u128);
+    airdrop_instantiate::fund(&mut deps, TEST_TOKEN, 3000u128);
+
+    airdrop_allocate_batch::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        vec![
+            (TEST_VOTER.to_string(), Uint128::from(100u128)),
+            (TEST_VOTER_2.to_string(), Uint128::from(200u128)),
+            (TEST_VOTER_3.to_string(), Uint128::from(300u128)),
+        ],
+    )
+    .unwrap();
+    airdrop_deallocate_batch::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_CREATOR, &[]),
+        0,
+        vec![
+            (TEST_VOTER.to_string(), Uint128::from(100u128)),
+            (TEST_VOTER_3.to_string(), Uint128::from(50u128)),
+        ],
+    )
+    .unwrap();
+
+    let response =
+        query_airdrop_allocations(deps.as_ref(), 0, None, None, Some(OrderBy::Asc)).unwrap();
+    let response: AirdropAllocationsResponse = from_binary(&response).unwrap();
+
+    // fully deallocated recipients are not listed, and the order follows the canonical address
+    let mut allocations = response.allocations.clone();
+    allocations.sort_by(|a, b| a.recipient.cmp(&b.recipient));
+    let mut expected = vec![
+        AirdropAllocationsResponseItem {
+            recipient: TEST_VOTER_2.to_string(),
+            amount: Uint128::from(200u128),
+        },
+        AirdropAllocationsResponseItem {
+            recipient: TEST_VOTER_3.to_string(),
+            amount: Uint128::from(250u128),
+        },
+    ];
+    expected.sort_by(|a, b| a.recipient.cmp(&b.recipient));
+    assert_eq!(allocations, expected);
+
+    let response_after_first = query_airdrop_allocations(
+        deps.as_ref(),
+        0,
+        Some(response.allocations[0].recipient.clone()),
+        None,
+        Some(OrderBy::Asc),
+    )
+    .unwrap();
+    let response_after_first: AirdropAllocationsResponse =
+        from_binary(&response_after_first).unwrap();
+    assert_eq!(response_after_first.allocations, response.allocations[1..]);
+}
+
+#[test]
+fn airdrop_allocations_not_found() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match query_airdrop_allocations(deps.as_ref(), 0, None, None, None) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropNotFound {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn airdrop_projection() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let (env, _, _) = airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+    let now = env.block.time.seconds();
+
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(100u128))],
+    )]);
+    staking_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER.to_string(),
+        Uint128::from(100u128),
+    )
+    .unwrap();
+    airdrop_update::exec(
+        &mut deps,
+        mock_env_height(env.block.height, now + 21600),
+        mock_info(TEST_VOTER, &[]),
+        Some(TEST_VOTER.to_string()),
+    )
+    .unwrap();
+
+    for (at_time, expected_time, expected_reward) in [
+        (now, now + 21600, 21600u128), // past time is treated as now
+        (now + 43200, now + 43200, 43200u128),
+        (now + 86400 * 2, now + 86400 * 2, 86400u128), // capped at the finish
+    ] {
+        let response = query_airdrop_projection(
+            deps.as_ref(),
+            mock_env_height(env.block.height, now + 21600),
+            TEST_VOTER.to_string(),
+            0,
+            at_time,
+        )
+        .unwrap();
+        let response: AirdropProjectionResponse = from_binary(&response).unwrap();
+        assert_eq!(
+            response,
+            AirdropProjectionResponse {
+                airdrop_id: 0,
+                reward_token: airdrop_instantiate::token(TEST_TOKEN),
+                at_time: expected_time,
+                share: Uint128::from(100u128),
+                total_share: Uint128::from(100u128),
+                reward: Uint128::from(expected_reward),
+            }
+        );
+    }
+}
+
+#[test]
+fn airdrop_projection_not_found() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match query_airdrop_projection(deps.as_ref(), mock_env(), TEST_VOTER.to_string(), 0, 0) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropNotFound {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn airdrop_not_found() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match query_airdrop(deps.as_ref(), 0) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AirdropNotFound {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn airdrop_projection_invalid_address() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    airdrop_instantiate::default(&mut deps, TEST_TOKEN, 86400);
+
+    match query_airdrop_projection(deps.as_ref(), mock_env(), "x".to_string(), 0, 0) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidAddress(address)) => assert_eq!(address, "x"),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -3,6 +3,7 @@ use cosmwasm_std::{from_binary, Uint128};
 use pylon_token::gov_msg::ClaimableAirdrop;
 use pylon_token::gov_resp::StakerResponse;
 
+use crate::error::ContractError;
 use crate::queries::bank::query_staker;
 use crate::testing::executions::{airdrop_instantiate, airdrop_update, staking_deposit};
 use crate::testing::{
@@ -59,3 +60,15 @@ fn stakers_filter_zero_reward() {
         )]
     );
 }
+
+#[test]
+fn staker_invalid_address() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match query_staker(deps.as_ref(), mock_env(), "x".to_string()) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidAddress(address)) => assert_eq!(address, "x"),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
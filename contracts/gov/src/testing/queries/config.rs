@@ -0,0 +1,109 @@
+use cosmwasm_std::testing::{mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{from_binary, Api, Decimal, Uint128};
+use pylon_token::common::OrderBy;
+use pylon_token::gov_resp::{ConfigFieldChangeResponse, ConfigHistoryResponse};
+
+use crate::queries::config::query_config_history;
+use crate::states::config::Config;
+use crate::states::poll::Poll;
+use crate::testing::executions::update_config;
+use crate::testing::{instantiate, mock_deps, mock_env_height, TEST_CREATOR};
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    // first change - by owner
+    update_config::exec(
+        &mut deps,
+        mock_env_height(100, 0),
+        mock_info(TEST_CREATOR, &[]),
+        update_config::Message {
+            quorum: Some(Decimal::percent(20)),
+            ..update_config::default_msg()
+        },
+    )
+    .unwrap();
+
+    // no-op change - not recorded
+    update_config::exec(
+        &mut deps,
+        mock_env_height(150, 0),
+        mock_info(TEST_CREATOR, &[]),
+        update_config::Message {
+            quorum: Some(Decimal::percent(20)),
+            ..update_config::default_msg()
+        },
+    )
+    .unwrap();
+
+    // second change - by gov itself while executing a poll
+    let mut config = Config::load(deps.as_ref().storage).unwrap();
+    config.owner = deps.api.addr_canonicalize(MOCK_CONTRACT_ADDR).unwrap();
+    Config::save(deps.as_mut().storage, &config).unwrap();
+    Poll::save_temp_id(deps.as_mut().storage, &3).unwrap();
+
+    let response = update_config::exec(
+        &mut deps,
+        mock_env_height(200, 0),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        update_config::Message {
+            threshold: Some(Decimal::percent(60)),
+            proposal_deposit: Some(Uint128::from(123u128)),
+            ..update_config::default_msg()
+        },
+    )
+    .unwrap();
+    assert!(response
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "poll_id" && attr.value == "3"));
+
+    let response = query_config_history(deps.as_ref(), None, None, None).unwrap();
+    let response: ConfigHistoryResponse = from_binary(&response).unwrap();
+    assert_eq!(response.history.len(), 2);
+
+    // default order is descending
+    let latest = &response.history[0];
+    assert_eq!(latest.id, 1);
+    assert_eq!(latest.sender, MOCK_CONTRACT_ADDR.to_string());
+    assert_eq!(latest.height, 200);
+    assert_eq!(latest.poll_id, Some(3));
+    assert_eq!(
+        latest.changes,
+        vec![
+            ConfigFieldChangeResponse {
+                field: "threshold".to_string(),
+                old_value: "0.5".to_string(),
+                new_value: "0.6".to_string(),
+            },
+            ConfigFieldChangeResponse {
+                field: "proposal_deposit".to_string(),
+                old_value: "10000000000".to_string(),
+                new_value: "123".to_string(),
+            },
+        ]
+    );
+
+    let first = &response.history[1];
+    assert_eq!(first.id, 0);
+    assert_eq!(first.sender, TEST_CREATOR.to_string());
+    assert_eq!(first.height, 100);
+    assert_eq!(first.poll_id, None);
+    assert_eq!(
+        first.changes,
+        vec![ConfigFieldChangeResponse {
+            field: "quorum".to_string(),
+            old_value: "0.3".to_string(),
+            new_value: "0.2".to_string(),
+        }]
+    );
+
+    // pagination
+    let response =
+        query_config_history(deps.as_ref(), Some(0), Some(1), Some(OrderBy::Asc)).unwrap();
+    let response: ConfigHistoryResponse = from_binary(&response).unwrap();
+    assert_eq!(response.history.len(), 1);
+    assert_eq!(response.history[0].id, 1);
+}
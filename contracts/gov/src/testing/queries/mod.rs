@@ -1,3 +1,6 @@
+mod airdrop;
 mod api_version;
 mod bank;
+mod config;
 mod poll;
+mod state;
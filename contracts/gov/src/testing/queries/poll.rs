@@ -1,14 +1,19 @@
 use crate::queries::poll::{
-    query_polls, query_polls_with_category_filter, query_polls_with_status_filter,
+    query_poll_tally, query_polls, query_polls_with_category_filter, query_polls_with_status_filter,
 };
-use cosmwasm_std::{from_binary, Api};
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{from_binary, Api, Decimal, Uint128};
 use pylon_token::gov_resp;
-use pylon_token::gov_resp::PollsResponse;
+use pylon_token::gov_resp::{PollTallyResponse, PollsResponse};
 
-use crate::states::poll::{Poll, PollCategory, PollStatus};
+use crate::error::ContractError;
+use crate::states::poll::{Poll, PollCategory, PollStatus, VoteOption};
 use crate::states::state::State;
+use crate::testing::executions::{poll_cast_vote, poll_create, poll_end, staking_deposit};
 use crate::testing::instantiate;
-use crate::testing::{mock_deps, MockDeps, TEST_CREATOR};
+use crate::testing::{
+    mock_deps, mock_env_height, MockDeps, TEST_CREATOR, TEST_VOTER, TEST_VOTER_2, VOTING_TOKEN,
+};
 
 fn save_poll(deps: &mut MockDeps, status: &PollStatus, category: &PollCategory) {
     let api = deps.api;
@@ -33,6 +38,7 @@ fn save_poll(deps: &mut MockDeps, status: &PollStatus, category: &PollCategory)
             deposit_amount: Default::default(),
             total_balance_at_end_poll: None,
             staked_amount: None,
+            multiple_choice: None,
         },
     )
     .unwrap();
@@ -51,6 +57,7 @@ fn setup_state(deps: &mut MockDeps) -> (Vec<PollStatus>, Vec<PollCategory>) {
         PollStatus::Rejected,
         PollStatus::Executed,
         PollStatus::Failed,
+        PollStatus::Concluded,
     ];
     let category_list = vec![
         PollCategory::Core,
@@ -124,3 +131,139 @@ fn polls_with_category_filter_default() {
             .any(|x| x.status == status.clone().into() && x.category != PollCategory::None.into()));
     }
 }
+
+#[test]
+fn poll_tally() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let default_init_msg = instantiate::default_msg();
+    let (env, _, _) = poll_create::with_execute_msgs(&mut deps);
+    let end_height = env.block.height + default_init_msg.voting_period;
+
+    const POLL_ID: u64 = 1;
+    const STAKE_AMOUNT: u128 = 1000;
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(
+            &MOCK_CONTRACT_ADDR.to_string(),
+            &Uint128::from(STAKE_AMOUNT * 2 + default_init_msg.proposal_deposit.u128()),
+        )],
+    )]);
+    staking_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER_2.to_string(),
+        Uint128::from(STAKE_AMOUNT),
+    )
+    .unwrap();
+    poll_cast_vote::with_stake(
+        &mut deps,
+        POLL_ID,
+        TEST_VOTER.to_string(),
+        VoteOption::Yes,
+        STAKE_AMOUNT,
+    );
+
+    let response =
+        query_poll_tally(deps.as_ref(), mock_env_height(end_height - 100, 0), POLL_ID).unwrap();
+    let response: PollTallyResponse = from_binary(&response).unwrap();
+    assert_eq!(
+        response,
+        PollTallyResponse {
+            poll_id: POLL_ID,
+            status: PollStatus::InProgress.into(),
+            staked_weight: Uint128::from(STAKE_AMOUNT * 2),
+            participation: Decimal::percent(50),
+            yes_ratio: Decimal::one(),
+            quorum: default_init_msg.quorum,
+            threshold: default_init_msg.threshold,
+            quorum_reached: true,
+            threshold_reached: true,
+            winning_option: None,
+            projected_status: PollStatus::Passed.into(),
+            blocks_until_end: 100,
+            blocks_until_timelock_end: 100 + default_init_msg.timelock_period,
+            blocks_until_executable: Some(100 + default_init_msg.timelock_period),
+        }
+    );
+
+    // tally must be same as the actual result
+    poll_end::default(&mut deps, end_height, POLL_ID);
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(
+            &MOCK_CONTRACT_ADDR.to_string(),
+            &Uint128::from(STAKE_AMOUNT * 2),
+        )],
+    )]);
+
+    let response = query_poll_tally(
+        deps.as_ref(),
+        mock_env_height(end_height + default_init_msg.timelock_period, 0),
+        POLL_ID,
+    )
+    .unwrap();
+    let response: PollTallyResponse = from_binary(&response).unwrap();
+    assert_eq!(response.status, PollStatus::Passed.into());
+    assert_eq!(response.projected_status, PollStatus::Passed.into());
+    assert_eq!(response.staked_weight, Uint128::from(STAKE_AMOUNT * 2));
+    assert_eq!(response.participation, Decimal::percent(50));
+    assert_eq!(response.blocks_until_end, 0);
+    assert_eq!(response.blocks_until_executable, Some(0));
+}
+
+#[test]
+fn poll_tally_quorum_not_reached() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let default_init_msg = instantiate::default_msg();
+    let (env, _, _) = poll_create::default(&mut deps);
+    let end_height = env.block.height + default_init_msg.voting_period;
+
+    const POLL_ID: u64 = 1;
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(
+            &MOCK_CONTRACT_ADDR.to_string(),
+            &Uint128::from(10000 + default_init_msg.proposal_deposit.u128()),
+        )],
+    )]);
+    staking_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_VOTER_2.to_string(),
+        Uint128::from(9000u128),
+    )
+    .unwrap();
+    poll_cast_vote::with_stake(
+        &mut deps,
+        POLL_ID,
+        TEST_VOTER.to_string(),
+        VoteOption::Yes,
+        1000,
+    );
+
+    let response =
+        query_poll_tally(deps.as_ref(), mock_env_height(end_height, 0), POLL_ID).unwrap();
+    let response: PollTallyResponse = from_binary(&response).unwrap();
+    assert_eq!(response.participation, Decimal::percent(10));
+    assert!(!response.quorum_reached);
+    assert!(!response.threshold_reached);
+    assert_eq!(response.projected_status, PollStatus::Rejected.into());
+    assert_eq!(response.blocks_until_executable, None);
+}
+
+#[test]
+fn poll_tally_not_found() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match query_poll_tally(deps.as_ref(), mock_env(), 1) {
+        Err(ContractError::PollNotFound {}) => (),
+        _ => panic!("Must return poll not found error"),
+    }
+}
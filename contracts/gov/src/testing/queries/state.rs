@@ -0,0 +1,96 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{from_binary, Decimal, Uint128};
+use pylon_token::common::OrderBy;
+use pylon_token::gov_resp::{ShareRateCheckpointResponse, ShareRateHistoryResponse, StateResponse};
+
+use crate::queries::state::{query_share_rate_history, query_state};
+use crate::testing::executions::staking_deposit;
+use crate::testing::{
+    instantiate, mock_deps, mock_env_height, MockDeps, TEST_VOTER, TEST_VOTER_2, VOTING_TOKEN,
+};
+
+fn stake(deps: &mut MockDeps, time: u64, total_balance: u128, staker: &str, amount: u128) {
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(
+            &MOCK_CONTRACT_ADDR.to_string(),
+            &Uint128::from(total_balance),
+        )],
+    )]);
+
+    staking_deposit::exec(
+        deps,
+        mock_env_height(12345, time),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        staker.to_string(),
+        Uint128::from(amount),
+    )
+    .unwrap();
+}
+
+#[test]
+fn balance_per_share() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    deps.querier.with_token_balances(&[(
+        &VOTING_TOKEN.to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+    )]);
+
+    let response = query_state(deps.as_ref(), mock_env()).unwrap();
+    let response: StateResponse = from_binary(&response).unwrap();
+    assert_eq!(response.total_balance, Uint128::zero());
+    assert_eq!(response.balance_per_share, Decimal::one());
+
+    let now = mock_env().block.time.seconds();
+    stake(&mut deps, now, 100, TEST_VOTER, 100);
+    // rewards are distributed to stakers
+    stake(&mut deps, now + 100, 300, TEST_VOTER_2, 100);
+
+    let response = query_state(deps.as_ref(), mock_env()).unwrap();
+    let response: StateResponse = from_binary(&response).unwrap();
+    assert_eq!(response.total_share, Uint128::from(150u128));
+    assert_eq!(response.total_balance, Uint128::from(300u128));
+    assert_eq!(
+        response.balance_per_share,
+        Decimal::from_ratio(2u128, 1u128)
+    );
+}
+
+#[test]
+fn share_rate_history() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let now = mock_env().block.time.seconds();
+    stake(&mut deps, now, 100, TEST_VOTER, 100);
+    stake(&mut deps, now + 100, 300, TEST_VOTER_2, 100); // within the interval
+    stake(&mut deps, now + 86400, 450, TEST_VOTER_2, 150);
+
+    let response = query_share_rate_history(deps.as_ref(), None, None, Some(OrderBy::Asc)).unwrap();
+    let response: ShareRateHistoryResponse = from_binary(&response).unwrap();
+    assert_eq!(
+        response.checkpoints,
+        vec![
+            ShareRateCheckpointResponse {
+                time: now,
+                height: 12345,
+                balance: Uint128::from(100u128),
+                share: Uint128::from(100u128),
+                balance_per_share: Decimal::one(),
+            },
+            ShareRateCheckpointResponse {
+                time: now + 86400,
+                height: 12345,
+                balance: Uint128::from(450u128),
+                share: Uint128::from(225u128),
+                balance_per_share: Decimal::from_ratio(2u128, 1u128),
+            },
+        ]
+    );
+
+    let response = query_share_rate_history(deps.as_ref(), Some(now + 86400), None, None).unwrap();
+    let response: ShareRateHistoryResponse = from_binary(&response).unwrap();
+    assert_eq!(response.checkpoints.len(), 1);
+    assert_eq!(response.checkpoints[0].time, now);
+}
@@ -2,20 +2,37 @@
 use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
-    from_binary, to_binary, Addr, Binary, CanonicalAddr, CosmosMsg, Decimal, Deps, DepsMut, Env,
-    MessageInfo, Response, StdError, StdResult, Uint128, WasmMsg,
+    from_binary, to_binary, Addr, Api, Binary, CanonicalAddr, Coin, CosmosMsg, Decimal, Deps,
+    DepsMut, Env, Fraction, MessageInfo, QuerierWrapper, Reply, Response, StdResult, Storage,
+    SubMsg, Uint128, Uint256, WasmMsg,
 };
 use cosmwasm_storage::singleton_read;
+use std::convert::TryInto;
 
-use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw20::{Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg, MinterResponse};
+use pylon_token::common::OrderBy;
+use pylon_token::gov_msg::{Cw20HookMsg as GovCw20HookMsg, QueryMsg as GovQueryMsg};
+use pylon_token::gov_resp::{
+    StakerResponse as GovStakerResponse, StateResponse as GovStateResponse,
+};
 use pylon_token::staking::{
     ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
-    StakerInfoResponse, StateResponse,
+    RewardStreamResponse, ScheduleMode, StakerInfoResponse, StakerInfosResponse, StateResponse,
+    StatsResponse, UnbondingResponse, UnbondingsResponse,
+};
+use terraswap::asset::{Asset, AssetInfo, PairInfo};
+use terraswap::pair::{
+    Cw20HookMsg as TerraswapCw20HookMsg, ExecuteMsg as TerraswapExecuteMsg,
+    QueryMsg as TerraswapQueryMsg,
 };
+use terraswap::querier::query_token_balance;
 
+use crate::error::ContractError;
 use crate::state::{
-    read_config, read_staker_info, read_state, remove_staker_info, store_config, store_staker_info,
-    store_state, ConfigV2, StakerInfoV2, StateV2,
+    read_all_unbondings, read_config, read_migrate_bond_context, read_staker_info,
+    read_staker_infos, read_state, read_unbondings, remove_migrate_bond_context,
+    remove_staker_info, store_config, store_migrate_bond_context, store_staker_info, store_state,
+    store_unbondings, ConfigV2, MigrateBondContext, RewardStream, StakerInfoV2, StateV2, Unbonding,
 };
 use crate::state::{ConfigV1, StateV1};
 
@@ -25,26 +42,31 @@ pub fn instantiate(
     env: Env,
     _info: MessageInfo,
     msg: InstantiateMsg,
-) -> StdResult<Response> {
-    store_config(
-        deps.storage,
-        &ConfigV2 {
-            governance: deps.api.addr_canonicalize(msg.governance.as_str())?,
-            pylon_token: deps.api.addr_canonicalize(&msg.pylon_token)?,
-            staking_token: vec![deps.api.addr_canonicalize(&msg.staking_token)?],
-            distribution_schedule: msg.distribution_schedule,
-        },
-    )?;
+) -> Result<Response, ContractError> {
+    let config = ConfigV2 {
+        governance: deps.api.addr_canonicalize(msg.governance.as_str())?,
+        pylon_token: deps.api.addr_canonicalize(&msg.pylon_token)?,
+        staking_token: vec![deps.api.addr_canonicalize(&msg.staking_token)?],
+        distribution_schedule: msg.distribution_schedule,
+        schedule_mode: msg.schedule_mode.unwrap_or_default(),
+        unbonding_period: 0,
+        boost_weight: Decimal::zero(),
+        extra_rewards: vec![],
+    };
+    store_config(deps.storage, &config)?;
 
+    let now = config.schedule_now(&env);
     store_state(
         deps.storage,
         0,
         &StateV2 {
             halted: false,
-            started_at: env.block.height,
-            last_distributed: env.block.height,
+            started_at: now,
+            last_distributed: now,
             total_bond_amount: Uint128::zero(),
             global_reward_index: Decimal::zero(),
+            extra_reward_indices: vec![],
+            total_working_amount: None,
         },
     )?;
 
@@ -52,14 +74,47 @@ pub fn instantiate(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::Receive(msg) => receive_cw20(deps, env, info, msg),
         ExecuteMsg::Unbond { amount } => unbond(deps, env, info, amount),
-        ExecuteMsg::Withdraw {} => withdraw(deps, env, info),
+        ExecuteMsg::EmergencyUnbond {} => emergency_unbond(deps, env, info),
+        ExecuteMsg::Withdraw { recipient } => withdraw(deps, env, info, recipient),
+        ExecuteMsg::WithdrawAndStakeGov {} => withdraw_and_stake_gov(deps, env, info),
+        ExecuteMsg::MigrateBond {
+            slippage_tolerance,
+            min_lp_amount,
+        } => migrate_bond(deps, env, info, slippage_tolerance, min_lp_amount),
         ExecuteMsg::MigrateStaking {
             new_staking_contract,
         } => migrate_staking(deps, env, info, new_staking_contract),
+        ExecuteMsg::UpdateConfig {
+            distribution_schedule,
+            unbonding_period,
+            boost_weight,
+        } => update_config(
+            deps,
+            env,
+            info,
+            distribution_schedule,
+            unbonding_period,
+            boost_weight,
+        ),
+        ExecuteMsg::ClaimUnbonded {} => claim_unbonded(deps, env, info),
+        ExecuteMsg::Kick { staker } => kick(deps, env, staker),
+        ExecuteMsg::AddRewardStream {
+            token,
+            distribution_schedule,
+        } => add_reward_stream(deps, env, info, token, distribution_schedule),
+        ExecuteMsg::UpdateRewardStream {
+            token,
+            distribution_schedule,
+        } => update_reward_stream(deps, env, info, token, distribution_schedule),
     }
 }
 
@@ -68,7 +123,7 @@ pub fn receive_cw20(
     env: Env,
     info: MessageInfo,
     cw20_msg: Cw20ReceiveMsg,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     let config: ConfigV2 = read_config(deps.storage)?;
 
     match from_binary(&cw20_msg.msg) {
@@ -77,17 +132,22 @@ pub fn receive_cw20(
             if config.staking_token[config.staking_token.len() - 1]
                 != deps.api.addr_canonicalize(info.sender.as_str())?
             {
-                return Err(StdError::generic_err("unauthorized"));
+                return Err(ContractError::Unauthorized {});
             }
 
             let cw20_sender = deps.api.addr_validate(&cw20_msg.sender)?;
             bond(deps, env, cw20_sender, cw20_msg.amount)
         }
-        Err(_) => Err(StdError::generic_err("data should be given")),
+        Err(_) => Err(ContractError::DataShouldBeGiven {}),
     }
 }
 
-pub fn bond(deps: DepsMut, env: Env, sender_addr: Addr, amount: Uint128) -> StdResult<Response> {
+pub fn bond(
+    deps: DepsMut,
+    env: Env,
+    sender_addr: Addr,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
     let mut resp = Response::new().add_attribute("action", "bond");
     let sender_addr_raw: CanonicalAddr = deps.api.addr_canonicalize(sender_addr.as_str())?;
 
@@ -96,42 +156,47 @@ pub fn bond(deps: DepsMut, env: Env, sender_addr: Addr, amount: Uint128) -> StdR
     let mut state: StateV2 = read_state(deps.storage, staking_token_version)?;
     let mut staker_info: StakerInfoV2 = read_staker_info(deps.storage, &sender_addr_raw)?;
 
-    compute_reward(&config, &mut state, env.block.height);
+    compute_reward(&config, &mut state, config.schedule_now(&env))?;
 
     // legacy
     if staking_token_version != staker_info.staking_token_version {
         let legacy_token_version = staker_info.staking_token_version;
-        let mut legacy_state = read_state(deps.storage, legacy_token_version)?;
-        compute_staker_reward(&legacy_state, &mut staker_info)?;
-
-        let legacy_unbond_amount = staker_info.bond_amount;
-        decrease_bond_amount(&mut legacy_state, &mut staker_info, legacy_unbond_amount)?;
-
-        // set to zero
-        staker_info.reward_index = state.global_reward_index;
-        staker_info.staking_token_version = staking_token_version;
-
-        store_state(deps.storage, legacy_token_version, &legacy_state)?;
+        let legacy_unbond_amount = release_legacy_bond(
+            deps.storage,
+            &state,
+            &mut staker_info,
+            staking_token_version,
+        )?;
 
         // force return to owner
-        resp = resp.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: deps
-                .api
-                .addr_humanize(&config.staking_token[legacy_token_version as usize])?
-                .to_string(),
-            msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                recipient: sender_addr.to_string(),
-                amount: legacy_unbond_amount,
-            })?,
-            funds: vec![],
-        }));
+        if !legacy_unbond_amount.is_zero() {
+            resp = resp.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: deps
+                    .api
+                    .addr_humanize(&config.staking_token[legacy_token_version as usize])?
+                    .to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: sender_addr.to_string(),
+                    amount: legacy_unbond_amount,
+                })?,
+                funds: vec![],
+            }));
+        }
     }
 
     // Compute global reward & staker reward
     compute_staker_reward(&state, &mut staker_info)?;
 
     // Increase bond_amount
-    increase_bond_amount(&mut state, &mut staker_info, amount);
+    increase_bond_amount(&mut state, &mut staker_info, amount)?;
+    apply_boost(
+        &deps.querier,
+        deps.api,
+        &config,
+        &mut state,
+        &mut staker_info,
+        &sender_addr,
+    )?;
 
     // Store updated state with staker's staker_info
     store_staker_info(deps.storage, &sender_addr_raw, &staker_info)?;
@@ -143,7 +208,36 @@ pub fn bond(deps: DepsMut, env: Env, sender_addr: Addr, amount: Uint128) -> StdR
     ]))
 }
 
-pub fn unbond(deps: DepsMut, env: Env, info: MessageInfo, amount: Uint128) -> StdResult<Response> {
+// moves staker out of the legacy token version, keeping pending rewards,
+// and returns the legacy staking token amount which was bonded
+fn release_legacy_bond(
+    storage: &mut dyn Storage,
+    state: &StateV2,
+    staker_info: &mut StakerInfoV2,
+    staking_token_version: u64,
+) -> StdResult<Uint128> {
+    let legacy_token_version = staker_info.staking_token_version;
+    let mut legacy_state = read_state(storage, legacy_token_version)?;
+    compute_staker_reward(&legacy_state, staker_info)?;
+
+    let legacy_unbond_amount = staker_info.bond_amount;
+    decrease_bond_amount(&mut legacy_state, staker_info, legacy_unbond_amount)?;
+
+    // set to zero
+    staker_info.reward_index = state.global_reward_index;
+    staker_info.staking_token_version = staking_token_version;
+
+    store_state(storage, legacy_token_version, &legacy_state)?;
+
+    Ok(legacy_unbond_amount)
+}
+
+pub fn unbond(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
     let config: ConfigV2 = read_config(deps.storage)?;
     let sender_addr_raw: CanonicalAddr = deps.api.addr_canonicalize(info.sender.as_str())?;
 
@@ -151,19 +245,27 @@ pub fn unbond(deps: DepsMut, env: Env, info: MessageInfo, amount: Uint128) -> St
     let mut state: StateV2 = read_state(deps.storage, staker_info.staking_token_version)?;
 
     if staker_info.bond_amount < amount {
-        return Err(StdError::generic_err("Cannot unbond more than bond amount"));
+        return Err(ContractError::ExceedsBond {});
     }
 
     // Compute global reward & staker reward
-    compute_reward(&config, &mut state, env.block.height);
+    compute_reward(&config, &mut state, config.schedule_now(&env))?;
     compute_staker_reward(&state, &mut staker_info)?;
 
     // Decrease bond_amount
     decrease_bond_amount(&mut state, &mut staker_info, amount)?;
+    apply_boost(
+        &deps.querier,
+        deps.api,
+        &config,
+        &mut state,
+        &mut staker_info,
+        &info.sender,
+    )?;
 
     // Store or remove updated rewards info
     // depends on the left pending reward and bond amount
-    if staker_info.pending_reward.is_zero() && staker_info.bond_amount.is_zero() {
+    if !staker_info.has_pending_reward() && staker_info.bond_amount.is_zero() {
         remove_staker_info(deps.storage, &sender_addr_raw);
     } else {
         store_staker_info(deps.storage, &sender_addr_raw, &staker_info)?;
@@ -172,85 +274,569 @@ pub fn unbond(deps: DepsMut, env: Env, info: MessageInfo, amount: Uint128) -> St
     // Store updated state
     store_state(deps.storage, staker_info.staking_token_version, &state)?;
 
-    Ok(Response::new()
-        .add_messages(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+    let resp = Response::new().add_attributes(vec![
+        ("action", "unbond"),
+        ("owner", info.sender.as_str()),
+        ("amount", amount.to_string().as_str()),
+    ]);
+
+    release_bond(
+        deps,
+        &env,
+        &config,
+        &info.sender,
+        staker_info.staking_token_version,
+        amount,
+        resp,
+    )
+}
+
+/// EmergencyUnbond
+/// Unbonds the whole bond without settling the staker reward, so that it
+/// stays available even if reward computation fails. Pending rewards are forfeited
+pub fn emergency_unbond(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config: ConfigV2 = read_config(deps.storage)?;
+    let sender_addr_raw: CanonicalAddr = deps.api.addr_canonicalize(info.sender.as_str())?;
+
+    let mut staker_info: StakerInfoV2 = read_staker_info(deps.storage, &sender_addr_raw)?;
+    let mut state: StateV2 = read_state(deps.storage, staker_info.staking_token_version)?;
+
+    let amount = staker_info.bond_amount;
+    if amount.is_zero() {
+        return Err(ContractError::NoBondToUnbond {});
+    }
+
+    // Settle global rewards up to now, so that the leaving working amount still
+    // counts for them. Its share is forfeited and never paid to remaining stakers.
+    // If the settlement overflows it is skipped, so that the bond can still be withdrawn
+    let mut settled = state.clone();
+    if compute_reward(&config, &mut settled, config.schedule_now(&env)).is_ok() {
+        state = settled;
+    }
+    decrease_bond_amount(&mut state, &mut staker_info, amount)?;
+
+    remove_staker_info(deps.storage, &sender_addr_raw);
+    store_state(deps.storage, staker_info.staking_token_version, &state)?;
+
+    let resp = Response::new().add_attributes(vec![
+        ("action", "emergency_unbond"),
+        ("owner", info.sender.as_str()),
+        ("amount", amount.to_string().as_str()),
+    ]);
+
+    release_bond(
+        deps,
+        &env,
+        &config,
+        &info.sender,
+        staker_info.staking_token_version,
+        amount,
+        resp,
+    )
+}
+
+// transfers unbonded staking tokens to owner,
+// or queues them if unbonding period is configured
+fn release_bond(
+    deps: DepsMut,
+    env: &Env,
+    config: &ConfigV2,
+    owner: &Addr,
+    staking_token_version: u64,
+    amount: Uint128,
+    resp: Response,
+) -> Result<Response, ContractError> {
+    if config.unbonding_period == 0 {
+        return Ok(resp.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: deps
                 .api
-                .addr_humanize(&config.staking_token[staker_info.staking_token_version as usize])?
+                .addr_humanize(&config.staking_token[staking_token_version as usize])?
                 .to_string(),
             msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                recipient: info.sender.to_string(),
+                recipient: owner.to_string(),
                 amount,
             })?,
             funds: vec![],
-        })])
-        .add_attributes(vec![
-            ("action", "unbond"),
-            ("owner", info.sender.as_str()),
-            ("amount", amount.to_string().as_str()),
-        ]))
+        })));
+    }
+
+    // queue until the unbonding period passes
+    let owner_raw = deps.api.addr_canonicalize(owner.as_str())?;
+    let release_at = config.schedule_now(env) + config.unbonding_period;
+    let mut unbondings = read_unbondings(deps.storage, &owner_raw)?;
+    unbondings.push(Unbonding {
+        staking_token_version,
+        amount,
+        release_at,
+    });
+    store_unbondings(deps.storage, &owner_raw, &unbondings)?;
+
+    Ok(resp.add_attribute("release_at", release_at.to_string()))
 }
 
-// withdraw rewards to executor
-pub fn withdraw(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Response> {
+// transfer matured unbondings to executor
+pub fn claim_unbonded(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config: ConfigV2 = read_config(deps.storage)?;
     let sender_addr_raw = deps.api.addr_canonicalize(info.sender.as_str())?;
+    let now = config.schedule_now(&env);
+
+    let (matured, unbondings): (Vec<Unbonding>, Vec<Unbonding>) =
+        read_unbondings(deps.storage, &sender_addr_raw)?
+            .into_iter()
+            .partition(|x| x.release_at <= now);
+    if matured.is_empty() {
+        return Err(ContractError::NoMaturedUnbonding {});
+    }
+    store_unbondings(deps.storage, &sender_addr_raw, &unbondings)?;
+
+    // amount per token version
+    let mut amounts = vec![Uint128::zero(); config.staking_token.len()];
+    for unbonding in matured.iter() {
+        amounts[unbonding.staking_token_version as usize] += unbonding.amount;
+    }
+
+    let mut messages = vec![];
+    for (token_version, amount) in amounts.iter().enumerate() {
+        if amount.is_zero() {
+            continue;
+        }
 
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: deps
+                .api
+                .addr_humanize(&config.staking_token[token_version])?
+                .to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: info.sender.to_string(),
+                amount: *amount,
+            })?,
+            funds: vec![],
+        }));
+    }
+
+    let total_amount: Uint128 = amounts.iter().sum();
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "claim_unbonded"),
+        ("owner", info.sender.as_str()),
+        ("amount", total_amount.to_string().as_str()),
+    ]))
+}
+
+/// Kick
+/// Recomputes the working amount of the staker, so that a boost which
+/// became stale as its gov stake decreased stops accruing rewards
+pub fn kick(deps: DepsMut, env: Env, staker: String) -> Result<Response, ContractError> {
     let config: ConfigV2 = read_config(deps.storage)?;
+    let staker_addr = deps.api.addr_validate(&staker)?;
+    let staker_addr_raw = deps.api.addr_canonicalize(staker_addr.as_str())?;
+
+    let mut staker_info: StakerInfoV2 = read_staker_info(deps.storage, &staker_addr_raw)?;
+    if staker_info.bond_amount.is_zero() {
+        return Err(ContractError::NoBondToKick {});
+    }
+    let mut state: StateV2 = read_state(deps.storage, staker_info.staking_token_version)?;
+
+    // settle rewards accrued by the previous working amount
+    compute_reward(&config, &mut state, config.schedule_now(&env))?;
+    compute_staker_reward(&state, &mut staker_info)?;
+    apply_boost(
+        &deps.querier,
+        deps.api,
+        &config,
+        &mut state,
+        &mut staker_info,
+        &staker_addr,
+    )?;
+
+    store_staker_info(deps.storage, &staker_addr_raw, &staker_info)?;
+    store_state(deps.storage, staker_info.staking_token_version, &state)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "kick"),
+        ("staker", staker_addr.as_str()),
+        (
+            "working_amount",
+            staker_info.working_amount().to_string().as_str(),
+        ),
+    ]))
+}
+
+// withdraw rewards to recipient, executor by default
+pub fn withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => info.sender.clone(),
+    };
+
+    let config: ConfigV2 = read_config(deps.storage)?;
+    let (amount, extra_rewards) =
+        take_pending_rewards(deps.storage, deps.api, &env, &config, &info.sender)?;
+
+    let mut messages = vec![];
+    if !amount.is_zero() {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: deps.api.addr_humanize(&config.pylon_token)?.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }));
+    }
+    messages.extend(extra_reward_messages(deps.api, &recipient, extra_rewards)?);
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "withdraw"),
+        ("owner", info.sender.as_str()),
+        ("amount", amount.to_string().as_str()),
+        ("recipient", recipient.as_str()),
+    ]))
+}
+
+// withdraw rewards and stake pylon token to gov on behalf of executor
+pub fn withdraw_and_stake_gov(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config: ConfigV2 = read_config(deps.storage)?;
+    let (amount, extra_rewards) =
+        take_pending_rewards(deps.storage, deps.api, &env, &config, &info.sender)?;
+    if amount.is_zero() && extra_rewards.is_empty() {
+        return Err(ContractError::NoPendingReward {});
+    }
+
+    let mut messages = vec![];
+    if !amount.is_zero() {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: deps.api.addr_humanize(&config.pylon_token)?.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Send {
+                contract: deps.api.addr_humanize(&config.governance)?.to_string(),
+                amount,
+                msg: to_binary(&GovCw20HookMsg::Stake {
+                    staker: Some(info.sender.to_string()),
+                })?,
+            })?,
+            funds: vec![],
+        }));
+    }
+    // extra reward tokens cannot be staked
+    messages.extend(extra_reward_messages(
+        deps.api,
+        &info.sender,
+        extra_rewards,
+    )?);
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "withdraw_and_stake_gov"),
+        ("owner", info.sender.as_str()),
+        ("amount", amount.to_string().as_str()),
+    ]))
+}
+
+// settles staker rewards and empties its pending pylon and extra rewards
+fn take_pending_rewards(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    env: &Env,
+    config: &ConfigV2,
+    sender: &Addr,
+) -> StdResult<(Uint128, Vec<(CanonicalAddr, Uint128)>)> {
+    let sender_addr_raw = api.addr_canonicalize(sender.as_str())?;
+
     let staking_token_version = (config.staking_token.len() - 1) as u64;
-    let mut state: StateV2 = read_state(deps.storage, staking_token_version)?;
-    let mut staker_info = read_staker_info(deps.storage, &sender_addr_raw)?;
+    let mut state: StateV2 = read_state(storage, staking_token_version)?;
+    let mut staker_info = read_staker_info(storage, &sender_addr_raw)?;
 
     // Compute global reward & staker reward
-    compute_reward(&config, &mut state, env.block.height);
+    compute_reward(config, &mut state, config.schedule_now(env))?;
     compute_staker_reward(&state, &mut staker_info)?;
 
     let amount = staker_info.pending_reward;
     staker_info.pending_reward = Uint128::zero();
 
+    let mut extra_rewards = vec![];
+    for reward in staker_info.extra_rewards.iter_mut() {
+        if reward.pending_reward.is_zero() {
+            continue;
+        }
+
+        extra_rewards.push((reward.token.clone(), reward.pending_reward));
+        reward.pending_reward = Uint128::zero();
+    }
+
     // Store or remove updated rewards info
     // depends on the left pending reward and bond amount
     if staker_info.bond_amount.is_zero() {
-        remove_staker_info(deps.storage, &sender_addr_raw);
+        remove_staker_info(storage, &sender_addr_raw);
     } else {
-        store_staker_info(deps.storage, &sender_addr_raw, &staker_info)?;
+        store_staker_info(storage, &sender_addr_raw, &staker_info)?;
     }
 
     // Store updated state
+    store_state(storage, staking_token_version, &state)?;
+
+    Ok((amount, extra_rewards))
+}
+
+fn extra_reward_messages(
+    api: &dyn Api,
+    recipient: &Addr,
+    extra_rewards: Vec<(CanonicalAddr, Uint128)>,
+) -> StdResult<Vec<CosmosMsg>> {
+    extra_rewards
+        .into_iter()
+        .map(|(token, amount)| {
+            Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: api.addr_humanize(&token)?.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }))
+        })
+        .collect()
+}
+
+const MIGRATE_BOND_WITHDRAW_REPLY_ID: u64 = 1;
+const MIGRATE_BOND_PROVIDE_REPLY_ID: u64 = 2;
+
+/// MigrateBond
+/// Moves the sender's legacy bond to the latest staking token by
+/// withdrawing liquidity from the legacy pair, providing it
+/// to the latest pair and bonding the resulting LP token
+pub fn migrate_bond(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    slippage_tolerance: Option<Decimal>,
+    min_lp_amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let sender_addr_raw = deps.api.addr_canonicalize(info.sender.as_str())?;
+
+    let config: ConfigV2 = read_config(deps.storage)?;
+    let staking_token_version = (config.staking_token.len() - 1) as u64;
+    let mut staker_info = read_staker_info(deps.storage, &sender_addr_raw)?;
+    if staker_info.staking_token_version == staking_token_version
+        || staker_info.bond_amount.is_zero()
+    {
+        return Err(ContractError::NoLegacyBond {});
+    }
+
+    let legacy_token = deps
+        .api
+        .addr_humanize(&config.staking_token[staker_info.staking_token_version as usize])?;
+    let staking_token = deps
+        .api
+        .addr_humanize(&config.staking_token[staking_token_version as usize])?;
+    let legacy_pair = query_lp_pair(deps.as_ref(), &legacy_token)?;
+    let pair = query_lp_pair(deps.as_ref(), &staking_token)?;
+    if !legacy_pair
+        .asset_infos
+        .iter()
+        .all(|x| pair.asset_infos.iter().any(|y| x.equal(y)))
+    {
+        return Err(ContractError::LegacyPairMismatch {});
+    }
+
+    let mut state: StateV2 = read_state(deps.storage, staking_token_version)?;
+    compute_reward(&config, &mut state, config.schedule_now(&env))?;
+
+    let amount = release_legacy_bond(
+        deps.storage,
+        &state,
+        &mut staker_info,
+        staking_token_version,
+    )?;
+    compute_staker_reward(&state, &mut staker_info)?;
+
+    store_staker_info(deps.storage, &sender_addr_raw, &staker_info)?;
     store_state(deps.storage, staking_token_version, &state)?;
 
+    // remember current balances to find out what withdrawal and provision return
+    let mut balances = vec![];
+    for asset_info in pair.asset_infos.iter() {
+        balances.push(Asset {
+            info: asset_info.clone(),
+            amount: asset_info.query_pool(&deps.querier, deps.api, env.contract.address.clone())?,
+        });
+    }
+    store_migrate_bond_context(
+        deps.storage,
+        &MigrateBondContext {
+            staker: sender_addr_raw,
+            pair: deps.api.addr_canonicalize(&pair.contract_addr)?,
+            balances,
+            lp_balance: query_token_balance(&deps.querier, staking_token, env.contract.address)?,
+            slippage_tolerance,
+            min_lp_amount: min_lp_amount.unwrap_or_default(),
+        },
+    )?;
+
     Ok(Response::new()
-        .add_messages(vec![CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: deps.api.addr_humanize(&config.pylon_token)?.to_string(),
-            msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                recipient: info.sender.to_string(),
-                amount,
-            })?,
-            funds: vec![],
-        })])
+        .add_submessage(SubMsg::reply_on_success(
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: legacy_token.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: legacy_pair.contract_addr,
+                    amount,
+                    msg: to_binary(&TerraswapCw20HookMsg::WithdrawLiquidity {})?,
+                })?,
+                funds: vec![],
+            }),
+            MIGRATE_BOND_WITHDRAW_REPLY_ID,
+        ))
         .add_attributes(vec![
-            ("action", "withdraw"),
+            ("action", "migrate_bond"),
             ("owner", info.sender.as_str()),
             ("amount", amount.to_string().as_str()),
         ]))
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        MIGRATE_BOND_WITHDRAW_REPLY_ID => migrate_bond_provide(deps, env),
+        MIGRATE_BOND_PROVIDE_REPLY_ID => migrate_bond_finish(deps, env),
+        _ => Err(ContractError::InvalidReplyId {}),
+    }
+}
+
+// provide withdrawn assets to the latest pair
+fn migrate_bond_provide(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let context = read_migrate_bond_context(deps.storage)?;
+    let pair = deps.api.addr_humanize(&context.pair)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut assets: Vec<Asset> = vec![];
+    let mut funds: Vec<Coin> = vec![];
+    for balance in context.balances.iter() {
+        let asset = Asset {
+            info: balance.info.clone(),
+            amount: balance
+                .info
+                .query_pool(&deps.querier, deps.api, env.contract.address.clone())?
+                .checked_sub(balance.amount)?,
+        };
+
+        match &asset.info {
+            AssetInfo::Token { contract_addr } => {
+                messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: contract_addr.to_string(),
+                    msg: to_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                        spender: pair.to_string(),
+                        amount: asset.amount,
+                        expires: None,
+                    })?,
+                    funds: vec![],
+                }));
+                assets.push(asset);
+            }
+            AssetInfo::NativeToken { .. } => {
+                // deduct tax first
+                let coin = asset.deduct_tax(&deps.querier)?;
+                assets.push(Asset {
+                    amount: coin.amount,
+                    ..asset
+                });
+                if !coin.amount.is_zero() {
+                    funds.push(coin);
+                }
+            }
+        }
+    }
+    funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_submessage(SubMsg::reply_on_success(
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: pair.to_string(),
+                msg: to_binary(&TerraswapExecuteMsg::ProvideLiquidity {
+                    assets: [assets[0].clone(), assets[1].clone()],
+                    slippage_tolerance: context.slippage_tolerance,
+                    receiver: None,
+                })?,
+                funds,
+            }),
+            MIGRATE_BOND_PROVIDE_REPLY_ID,
+        ))
+        .add_attributes(vec![
+            ("action", "migrate_bond_provide"),
+            ("assets", &format!("{}, {}", assets[0], assets[1])),
+        ]))
+}
+
+// bond provided LP token to the staker
+fn migrate_bond_finish(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config: ConfigV2 = read_config(deps.storage)?;
+    let context = read_migrate_bond_context(deps.storage)?;
+
+    let staking_token = deps
+        .api
+        .addr_humanize(&config.staking_token[config.staking_token.len() - 1])?;
+    let amount = query_token_balance(&deps.querier, staking_token, env.contract.address.clone())?
+        .checked_sub(context.lp_balance)?;
+    if amount < context.min_lp_amount {
+        return Err(ContractError::InsufficientLiquidity {});
+    }
+    remove_migrate_bond_context(deps.storage);
+    let staker = deps.api.addr_humanize(&context.staker)?;
+
+    bond(deps, env, staker, amount)
+}
+
+// terraswap pair is the minter of its liquidity token
+fn query_lp_pair(deps: Deps, lp_token: &Addr) -> Result<PairInfo, ContractError> {
+    let minter: Option<MinterResponse> = deps
+        .querier
+        .query_wasm_smart(lp_token.to_string(), &Cw20QueryMsg::Minter {})?;
+    let minter = match minter {
+        Some(minter) => minter.minter,
+        None => return Err(ContractError::NoMinter {}),
+    };
+
+    let pair: PairInfo = deps
+        .querier
+        .query_wasm_smart(minter, &TerraswapQueryMsg::Pair {})?;
+    if pair.liquidity_token != lp_token.as_str() {
+        return Err(ContractError::NotLiquidityToken {});
+    }
+
+    Ok(pair)
+}
+
 pub fn migrate_staking(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     new_staking_token: String,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     let mut config: ConfigV2 = read_config(deps.storage)?;
     if config.governance != deps.api.addr_canonicalize(info.sender.as_str())? {
-        return Err(StdError::generic_err("unauthorized"));
+        return Err(ContractError::Unauthorized {});
     }
 
     let mut state: StateV2 = read_state(deps.storage, (config.staking_token.len() - 1) as u64)?;
 
-    // compute global reward, sets last_distributed_height to env.block.height
-    compute_reward(&config, &mut state, env.block.height);
+    // compute global reward, sets last_distributed to the current schedule point
+    let now = config.schedule_now(&env);
+    compute_reward(&config, &mut state, now)?;
 
-    let block_height = env.block.height;
     let legacy_token_version = (config.staking_token.len() - 1) as u64;
     let new_token_version = legacy_token_version + 1;
     state.halted = true;
@@ -267,19 +853,183 @@ pub fn migrate_staking(
         new_token_version,
         &StateV2 {
             halted: false,
-            started_at: block_height,
-            last_distributed: block_height,
+            started_at: now,
+            last_distributed: now,
             total_bond_amount: Uint128::zero(),
             global_reward_index: Decimal::zero(),
+            extra_reward_indices: vec![],
+            total_working_amount: None,
         },
     )?;
 
     Ok(Response::new().add_attributes(vec![("action", "migrate_staking")]))
 }
 
-fn increase_bond_amount(state: &mut StateV2, staker_info: &mut StakerInfoV2, amount: Uint128) {
+pub fn update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    distribution_schedule: Option<Vec<(u64, u64, Uint128)>>,
+    unbonding_period: Option<u64>,
+    boost_weight: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let mut config: ConfigV2 = read_config(deps.storage)?;
+    if config.governance != deps.api.addr_canonicalize(info.sender.as_str())? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(distribution_schedule) = distribution_schedule {
+        let now = config.schedule_now(&env);
+        validate_distribution_schedule(&config.distribution_schedule, &distribution_schedule, now)?;
+
+        // settle rewards with the previous schedule before switching
+        let staking_token_version = (config.staking_token.len() - 1) as u64;
+        let mut state: StateV2 = read_state(deps.storage, staking_token_version)?;
+        compute_reward(&config, &mut state, now)?;
+        store_state(deps.storage, staking_token_version, &state)?;
+
+        config.distribution_schedule = distribution_schedule;
+    }
+
+    // only applies to unbondings requested afterwards
+    if let Some(unbonding_period) = unbonding_period {
+        config.unbonding_period = unbonding_period;
+    }
+
+    // existing working amounts are updated as stakers are checkpointed or kicked
+    if let Some(boost_weight) = boost_weight {
+        if boost_weight > Decimal::one() {
+            return Err(ContractError::InvalidBoostWeight {});
+        }
+
+        config.boost_weight = boost_weight;
+    }
+
+    store_config(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![("action", "update_config")]))
+}
+
+pub fn add_reward_stream(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token: String,
+    distribution_schedule: Vec<(u64, u64, Uint128)>,
+) -> Result<Response, ContractError> {
+    let mut config: ConfigV2 = read_config(deps.storage)?;
+    if config.governance != deps.api.addr_canonicalize(info.sender.as_str())? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let token_raw = deps.api.addr_canonicalize(&token)?;
+    if token_raw == config.pylon_token || config.extra_rewards.iter().any(|x| x.token == token_raw)
+    {
+        return Err(ContractError::RewardStreamExists {});
+    }
+
+    let now = config.schedule_now(&env);
+    validate_distribution_schedule(&[], &distribution_schedule, now)?;
+
+    // settle rewards so the new stream only accrues from now on
+    let staking_token_version = (config.staking_token.len() - 1) as u64;
+    let mut state: StateV2 = read_state(deps.storage, staking_token_version)?;
+    compute_reward(&config, &mut state, now)?;
+    store_state(deps.storage, staking_token_version, &state)?;
+
+    config.extra_rewards.push(RewardStream {
+        token: token_raw,
+        distribution_schedule,
+    });
+    store_config(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "add_reward_stream"),
+        ("token", token.as_str()),
+    ]))
+}
+
+pub fn update_reward_stream(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token: String,
+    distribution_schedule: Vec<(u64, u64, Uint128)>,
+) -> Result<Response, ContractError> {
+    let mut config: ConfigV2 = read_config(deps.storage)?;
+    if config.governance != deps.api.addr_canonicalize(info.sender.as_str())? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let token_raw = deps.api.addr_canonicalize(&token)?;
+    let index = config
+        .extra_rewards
+        .iter()
+        .position(|x| x.token == token_raw)
+        .ok_or(ContractError::RewardStreamNotFound {})?;
+
+    let now = config.schedule_now(&env);
+    validate_distribution_schedule(
+        &config.extra_rewards[index].distribution_schedule,
+        &distribution_schedule,
+        now,
+    )?;
+
+    // settle rewards with the previous schedule before switching
+    let staking_token_version = (config.staking_token.len() - 1) as u64;
+    let mut state: StateV2 = read_state(deps.storage, staking_token_version)?;
+    compute_reward(&config, &mut state, now)?;
+    store_state(deps.storage, staking_token_version, &state)?;
+
+    // the stream is kept to preserve its reward index for pending rewards
+    config.extra_rewards[index].distribution_schedule = distribution_schedule;
+    store_config(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_reward_stream"),
+        ("token", token.as_str()),
+    ]))
+}
+
+// ranges must be non-empty, ordered and non-overlapping,
+// and ranges which already started cannot be added, changed or removed
+fn validate_distribution_schedule(
+    current: &[(u64, u64, Uint128)],
+    schedule: &[(u64, u64, Uint128)],
+    now: u64,
+) -> Result<(), ContractError> {
+    if schedule.iter().any(|s| s.0 >= s.1) {
+        return Err(ContractError::InvalidScheduleRange {});
+    }
+
+    if schedule.windows(2).any(|w| w[0].1 > w[1].0) {
+        return Err(ContractError::OverlappingSchedule {});
+    }
+
+    let elapsed = |s: &&(u64, u64, Uint128)| s.0 < now;
+    if !current
+        .iter()
+        .filter(elapsed)
+        .eq(schedule.iter().filter(elapsed))
+    {
+        return Err(ContractError::ElapsedScheduleModified {});
+    }
+
+    Ok(())
+}
+
+// newly bonded amount is unboosted until apply_boost
+fn increase_bond_amount(
+    state: &mut StateV2,
+    staker_info: &mut StakerInfoV2,
+    amount: Uint128,
+) -> StdResult<()> {
+    let working_amount = staker_info.working_amount() + amount;
+    set_working_amount(state, staker_info, working_amount)?;
+
     state.total_bond_amount += amount;
     staker_info.bond_amount += amount;
+    Ok(())
 }
 
 fn decrease_bond_amount(
@@ -287,54 +1037,182 @@ fn decrease_bond_amount(
     staker_info: &mut StakerInfoV2,
     amount: Uint128,
 ) -> StdResult<()> {
+    let bond_amount = staker_info.bond_amount.checked_sub(amount)?;
+    let working_amount = std::cmp::min(staker_info.working_amount(), bond_amount);
+    set_working_amount(state, staker_info, working_amount)?;
+
     state.total_bond_amount = state.total_bond_amount.checked_sub(amount)?;
-    staker_info.bond_amount = staker_info.bond_amount.checked_sub(amount)?;
+    staker_info.bond_amount = bond_amount;
     Ok(())
 }
 
+// replaces working amount of the staker, keeping the total in sync
+fn set_working_amount(
+    state: &mut StateV2,
+    staker_info: &mut StakerInfoV2,
+    working_amount: Uint128,
+) -> StdResult<()> {
+    state.total_working_amount = Some(
+        state
+            .working_amount()
+            .checked_sub(staker_info.working_amount())?
+            + working_amount,
+    );
+    staker_info.working_amount = Some(working_amount);
+    Ok(())
+}
+
+fn apply_boost(
+    querier: &QuerierWrapper,
+    api: &dyn Api,
+    config: &ConfigV2,
+    state: &mut StateV2,
+    staker_info: &mut StakerInfoV2,
+    staker: &Addr,
+) -> StdResult<()> {
+    let working_amount = compute_working_amount(querier, api, config, state, staker_info, staker)?;
+    set_working_amount(state, staker_info, working_amount)
+}
+
+// Curve-style working amount with boost weight w:
+// min(bond, bond * (1 - w) + total_bond * w * gov_balance / gov_total_balance)
+// so that gov stakers earn up to 1 / (1 - w) times the unboosted rewards
+fn compute_working_amount(
+    querier: &QuerierWrapper,
+    api: &dyn Api,
+    config: &ConfigV2,
+    state: &StateV2,
+    staker_info: &StakerInfoV2,
+    staker: &Addr,
+) -> StdResult<Uint128> {
+    if config.boost_weight.is_zero() || staker_info.bond_amount.is_zero() {
+        return Ok(staker_info.bond_amount);
+    }
+
+    let governance = api.addr_humanize(&config.governance)?;
+    let gov_state: GovStateResponse =
+        querier.query_wasm_smart(governance.to_string(), &GovQueryMsg::State {})?;
+
+    let mut working_amount = staker_info.bond_amount * (Decimal::one() - config.boost_weight);
+    if !gov_state.total_balance.is_zero() {
+        let gov_staker: GovStakerResponse = querier.query_wasm_smart(
+            governance.to_string(),
+            &GovQueryMsg::Staker {
+                address: staker.to_string(),
+            },
+        )?;
+
+        working_amount += state.total_bond_amount
+            * Decimal::from_ratio(gov_staker.balance, gov_state.total_balance)
+            * config.boost_weight;
+    }
+
+    Ok(std::cmp::min(working_amount, staker_info.bond_amount))
+}
+
 // compute distributed rewards and update global reward index
-fn compute_reward(config: &ConfigV2, state: &mut StateV2, block_height: u64) {
+fn compute_reward(config: &ConfigV2, state: &mut StateV2, now: u64) -> StdResult<()> {
     if state.halted {
-        return;
+        return Ok(());
     }
 
-    if state.total_bond_amount.is_zero() {
-        state.last_distributed = block_height;
-        return;
+    let total_working_amount = state.working_amount();
+    if total_working_amount.is_zero() {
+        state.last_distributed = now;
+        return Ok(());
     }
 
+    let distributed_amount =
+        compute_distributed_amount(&config.distribution_schedule, state.last_distributed, now)?;
+    state.global_reward_index = checked_add_decimal(
+        state.global_reward_index,
+        checked_from_ratio(distributed_amount, total_working_amount)?,
+    )?;
+
+    for stream in config.extra_rewards.iter() {
+        let distributed_amount =
+            compute_distributed_amount(&stream.distribution_schedule, state.last_distributed, now)?;
+        let reward_index = state.extra_reward_index_mut(&stream.token);
+        *reward_index = checked_add_decimal(
+            *reward_index,
+            checked_from_ratio(distributed_amount, total_working_amount)?,
+        )?;
+    }
+
+    state.last_distributed = now;
+
+    Ok(())
+}
+
+// amount of rewards scheduled between last_distributed and now,
+// both measured in the unit of the schedule mode
+fn compute_distributed_amount(
+    distribution_schedule: &[(u64, u64, Uint128)],
+    last_distributed: u64,
+    now: u64,
+) -> StdResult<Uint128> {
     let mut distributed_amount: Uint128 = Uint128::zero();
-    for s in config.distribution_schedule.iter() {
-        if s.0 > block_height || s.1 < state.last_distributed {
+    for s in distribution_schedule.iter() {
+        if s.0 > now || s.1 < last_distributed {
             continue;
         }
 
-        // min(s.1, block_height) - max(s.0, last_distributed)
-        let passed_blocks =
-            std::cmp::min(s.1, block_height) - std::cmp::max(s.0, state.last_distributed);
+        // min(s.1, now) - max(s.0, last_distributed)
+        let passed = std::cmp::min(s.1, now) - std::cmp::max(s.0, last_distributed);
 
-        let num_blocks = s.1 - s.0;
-        let distribution_amount_per_block: Decimal = Decimal::from_ratio(s.2, num_blocks);
-        distributed_amount += distribution_amount_per_block * Uint128::from(passed_blocks as u128);
+        let duration = s.1 - s.0;
+        let distribution_amount_per_unit: Decimal =
+            checked_from_ratio(s.2, Uint128::from(duration))?;
+        distributed_amount = distributed_amount.checked_add(checked_mul_decimal(
+            Uint128::from(passed),
+            distribution_amount_per_unit,
+        )?)?;
     }
 
-    state.last_distributed = block_height;
-    state.global_reward_index = state.global_reward_index
-        + Decimal::from_ratio(distributed_amount, state.total_bond_amount);
+    Ok(distributed_amount)
+}
+
+// Decimal math of cosmwasm-std panics on overflow, these return an error instead
+const DECIMAL_FRACTIONAL: u128 = 1_000_000_000_000_000_000;
+
+fn checked_from_ratio(numerator: Uint128, denominator: Uint128) -> StdResult<Decimal> {
+    let atomics: Uint128 =
+        (numerator.full_mul(DECIMAL_FRACTIONAL) / Uint256::from(denominator)).try_into()?;
+    Ok(Decimal::from_ratio(atomics, DECIMAL_FRACTIONAL))
+}
+
+fn checked_add_decimal(a: Decimal, b: Decimal) -> StdResult<Decimal> {
+    let atomics = Uint128::from(a.numerator()).checked_add(Uint128::from(b.numerator()))?;
+    Ok(Decimal::from_ratio(atomics, DECIMAL_FRACTIONAL))
+}
+
+fn checked_mul_decimal(a: Uint128, b: Decimal) -> StdResult<Uint128> {
+    Ok((a.full_mul(b.numerator()) / Uint256::from(DECIMAL_FRACTIONAL)).try_into()?)
 }
 
 // withdraw reward to pending reward
 fn compute_staker_reward(state: &StateV2, staker_info: &mut StakerInfoV2) -> StdResult<()> {
-    let pending_reward = (staker_info.bond_amount * state.global_reward_index)
-        .checked_sub(staker_info.bond_amount * staker_info.reward_index)?;
+    let working_amount = staker_info.working_amount();
+    let pending_reward = (working_amount * state.global_reward_index)
+        .checked_sub(working_amount * staker_info.reward_index)?;
 
     staker_info.reward_index = state.global_reward_index;
     staker_info.pending_reward += pending_reward;
+
+    for (token, global_reward_index) in state.extra_reward_indices.iter() {
+        let reward = staker_info.extra_reward_mut(token);
+        let pending_reward = (working_amount * *global_reward_index)
+            .checked_sub(working_amount * reward.reward_index)?;
+
+        reward.reward_index = *global_reward_index;
+        reward.pending_reward += pending_reward;
+    }
+
     Ok(())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
         QueryMsg::State {
@@ -345,6 +1223,23 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             staker,
             block_height,
         } => to_binary(&query_staker_info(deps, staker, block_height)?),
+        QueryMsg::StakerInfos {
+            start_after,
+            limit,
+            order_by,
+            token_version,
+        } => to_binary(&query_staker_infos(
+            deps,
+            env,
+            start_after,
+            limit,
+            order_by,
+            token_version,
+        )?),
+        QueryMsg::Stats { start_after, limit } => {
+            to_binary(&query_stats(deps, env, start_after, limit)?)
+        }
+        QueryMsg::Unbondings { staker } => to_binary(&query_unbondings(deps, env, staker)?),
     }
 }
 
@@ -355,9 +1250,22 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         staking_token: config
             .staking_token
             .iter()
-            .map(|token| deps.api.addr_humanize(token).unwrap().to_string())
-            .collect(),
+            .map(|token| Ok(deps.api.addr_humanize(token)?.to_string()))
+            .collect::<StdResult<Vec<String>>>()?,
         distribution_schedule: config.distribution_schedule,
+        schedule_mode: config.schedule_mode,
+        unbonding_period: config.unbonding_period,
+        boost_weight: config.boost_weight,
+        extra_rewards: config
+            .extra_rewards
+            .iter()
+            .map(|stream| {
+                Ok(RewardStreamResponse {
+                    token: deps.api.addr_humanize(&stream.token)?.to_string(),
+                    distribution_schedule: stream.distribution_schedule.clone(),
+                })
+            })
+            .collect::<StdResult<Vec<RewardStreamResponse>>>()?,
     };
 
     Ok(resp)
@@ -375,7 +1283,7 @@ pub fn query_state(
     )?;
     if let Some(block_height) = block_height {
         let config = read_config(deps.storage)?;
-        compute_reward(&config, &mut state, block_height);
+        compute_reward(&config, &mut state, block_height)?;
     }
 
     Ok(StateResponse {
@@ -383,6 +1291,7 @@ pub fn query_state(
         started_at: state.started_at,
         last_distributed: state.last_distributed,
         total_bond_amount: state.total_bond_amount,
+        total_working_amount: state.working_amount(),
         global_reward_index: state.global_reward_index,
     })
 }
@@ -394,26 +1303,200 @@ pub fn query_staker_info(
 ) -> StdResult<StakerInfoResponse> {
     let staker_raw = deps.api.addr_canonicalize(&staker)?;
 
+    let config = read_config(deps.storage)?;
     let mut staker_info: StakerInfoV2 = read_staker_info(deps.storage, &staker_raw)?;
     if let Some(block_height) = block_height {
-        let config = read_config(deps.storage)?;
         let mut state = read_state(deps.storage, staker_info.staking_token_version)?;
 
-        compute_reward(&config, &mut state, block_height);
+        compute_reward(&config, &mut state, block_height)?;
         compute_staker_reward(&state, &mut staker_info)?;
     }
 
+    to_staker_info_response(deps, &config, staker, &staker_info)
+}
+
+pub fn query_staker_infos(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    order_by: Option<OrderBy>,
+    token_version: Option<u64>,
+) -> StdResult<StakerInfosResponse> {
+    let config = read_config(deps.storage)?;
+    let start_after = match start_after {
+        Some(start_after) => Some(deps.api.addr_canonicalize(&start_after)?),
+        None => None,
+    };
+    let states = read_states_at(deps, &config, config.schedule_now(&env))?;
+
+    let (staker_infos, last_staker) =
+        read_staker_infos(deps.storage, start_after, limit, order_by, token_version)?;
+    let stakers = staker_infos
+        .into_iter()
+        .map(|(staker_raw, mut staker_info)| {
+            compute_staker_reward(
+                &states[staker_info.staking_token_version as usize],
+                &mut staker_info,
+            )?;
+            to_staker_info_response(
+                deps,
+                &config,
+                deps.api.addr_humanize(&staker_raw)?.to_string(),
+                &staker_info,
+            )
+        })
+        .collect::<StdResult<Vec<StakerInfoResponse>>>()?;
+
+    Ok(StakerInfosResponse {
+        stakers,
+        last_staker: match last_staker {
+            Some(staker_raw) => Some(deps.api.addr_humanize(&staker_raw)?.to_string()),
+            None => None,
+        },
+    })
+}
+
+pub fn query_stats(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<StatsResponse> {
+    let config = read_config(deps.storage)?;
+    let start_after = match start_after {
+        Some(start_after) => Some(deps.api.addr_canonicalize(&start_after)?),
+        None => None,
+    };
+    let now = config.schedule_now(&env);
+    let states = read_states_at(deps, &config, now)?;
+
+    let reward_tokens = reward_tokens_of(deps, &config)?;
+    let mut remaining_emissions = vec![compute_distributed_amount(
+        &config.distribution_schedule,
+        now,
+        u64::MAX,
+    )?];
+    for stream in config.extra_rewards.iter() {
+        remaining_emissions.push(compute_distributed_amount(
+            &stream.distribution_schedule,
+            now,
+            u64::MAX,
+        )?);
+    }
+
+    let (staker_infos, last_staker) =
+        read_staker_infos(deps.storage, start_after, limit, Some(OrderBy::Asc), None)?;
+    let mut total_pending_rewards = vec![Uint128::zero(); reward_tokens.len()];
+    for (_, mut staker_info) in staker_infos.iter().cloned() {
+        compute_staker_reward(
+            &states[staker_info.staking_token_version as usize],
+            &mut staker_info,
+        )?;
+
+        let pending_rewards = pending_rewards_of(&config, &staker_info);
+        for (total, pending) in total_pending_rewards.iter_mut().zip(pending_rewards) {
+            *total += pending;
+        }
+    }
+
+    Ok(StatsResponse {
+        num_stakers: staker_infos.len() as u64,
+        last_staker: match last_staker {
+            Some(staker_raw) => Some(deps.api.addr_humanize(&staker_raw)?.to_string()),
+            None => None,
+        },
+        total_pending_rewards: reward_tokens
+            .iter()
+            .cloned()
+            .zip(total_pending_rewards)
+            .collect(),
+        remaining_emissions: reward_tokens.into_iter().zip(remaining_emissions).collect(),
+    })
+}
+
+pub fn query_unbondings(deps: Deps, env: Env, staker: String) -> StdResult<UnbondingsResponse> {
+    let config = read_config(deps.storage)?;
+    let now = config.schedule_now(&env);
+    let unbondings = read_unbondings(deps.storage, &deps.api.addr_canonicalize(&staker)?)?;
+
+    Ok(UnbondingsResponse {
+        claimable_amount: unbondings
+            .iter()
+            .filter(|x| x.release_at <= now)
+            .map(|x| x.amount)
+            .sum(),
+        unbondings: unbondings
+            .into_iter()
+            .map(|x| UnbondingResponse {
+                staking_token_version: x.staking_token_version,
+                amount: x.amount,
+                release_at: x.release_at,
+            })
+            .collect(),
+    })
+}
+
+// states of every token version with rewards computed up to now
+fn read_states_at(deps: Deps, config: &ConfigV2, now: u64) -> StdResult<Vec<StateV2>> {
+    (0..config.staking_token.len() as u64)
+        .map(|token_version| {
+            let mut state = read_state(deps.storage, token_version)?;
+            compute_reward(config, &mut state, now)?;
+            Ok(state)
+        })
+        .collect()
+}
+
+// pylon token followed by the token of each extra reward stream
+fn reward_tokens_of(deps: Deps, config: &ConfigV2) -> StdResult<Vec<String>> {
+    let mut reward_tokens = vec![deps.api.addr_humanize(&config.pylon_token)?.to_string()];
+    for stream in config.extra_rewards.iter() {
+        reward_tokens.push(deps.api.addr_humanize(&stream.token)?.to_string());
+    }
+
+    Ok(reward_tokens)
+}
+
+// pending rewards in the order of reward_tokens_of
+fn pending_rewards_of(config: &ConfigV2, staker_info: &StakerInfoV2) -> Vec<Uint128> {
+    let mut pending_rewards = vec![staker_info.pending_reward];
+    for stream in config.extra_rewards.iter() {
+        pending_rewards.push(
+            staker_info
+                .extra_rewards
+                .iter()
+                .find(|x| x.token == stream.token)
+                .map(|x| x.pending_reward)
+                .unwrap_or_default(),
+        );
+    }
+
+    pending_rewards
+}
+
+fn to_staker_info_response(
+    deps: Deps,
+    config: &ConfigV2,
+    staker: String,
+    staker_info: &StakerInfoV2,
+) -> StdResult<StakerInfoResponse> {
     Ok(StakerInfoResponse {
         staker,
         staking_token_version: staker_info.staking_token_version,
         reward_index: staker_info.reward_index,
         bond_amount: staker_info.bond_amount,
+        working_amount: staker_info.working_amount(),
         pending_reward: staker_info.pending_reward,
+        pending_rewards: reward_tokens_of(deps, config)?
+            .into_iter()
+            .zip(pending_rewards_of(config, staker_info))
+            .collect(),
     })
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     match msg {
         MigrateMsg::Migrate { governance } => {
             let legacy_config: ConfigV1 = singleton_read(deps.storage, b"config").load()?;
@@ -425,6 +1508,10 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response>
                     pylon_token: legacy_config.pylon_token,
                     staking_token: vec![legacy_config.staking_token],
                     distribution_schedule: legacy_config.distribution_schedule,
+                    schedule_mode: ScheduleMode::Height,
+                    unbonding_period: 0,
+                    boost_weight: Decimal::zero(),
+                    extra_rewards: vec![],
                 },
             )?;
 
@@ -438,11 +1525,89 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response>
                     last_distributed: legacy_state.last_distributed,
                     total_bond_amount: legacy_state.total_bond_amount,
                     global_reward_index: legacy_state.global_reward_index,
+                    extra_reward_indices: vec![],
+                    total_working_amount: None,
                 },
             )?;
         }
         MigrateMsg::General {} => {}
+        MigrateMsg::TimeSchedule { seconds_per_block } => {
+            migrate_time_schedule(deps, env, seconds_per_block)?;
+        }
     }
 
     Ok(Response::default())
 }
+
+fn migrate_time_schedule(
+    deps: DepsMut,
+    env: Env,
+    seconds_per_block: Decimal,
+) -> Result<(), ContractError> {
+    let mut config: ConfigV2 = read_config(deps.storage)?;
+    if config.schedule_mode == ScheduleMode::Time {
+        return Err(ContractError::AlreadyTimeBased {});
+    }
+    if seconds_per_block.is_zero() {
+        return Err(ContractError::InvalidSecondsPerBlock {});
+    }
+
+    // settle rewards by height before switching the unit
+    let staking_token_version = (config.staking_token.len() - 1) as u64;
+    let mut state: StateV2 = read_state(deps.storage, staking_token_version)?;
+    compute_reward(&config, &mut state, env.block.height)?;
+
+    let to_time = |height: u64| -> u64 {
+        let now = env.block.time.seconds();
+        if height >= env.block.height {
+            let diff = Uint128::from(height - env.block.height) * seconds_per_block;
+            now + diff.u128() as u64
+        } else {
+            let diff = Uint128::from(env.block.height - height) * seconds_per_block;
+            now.saturating_sub(diff.u128() as u64)
+        }
+    };
+    let convert =
+        |schedule: &[(u64, u64, Uint128)]| -> Result<Vec<(u64, u64, Uint128)>, ContractError> {
+            schedule
+                .iter()
+                .map(|s| {
+                    let (start, end) = (to_time(s.0), to_time(s.1));
+                    if start >= end {
+                        return Err(ContractError::InvalidScheduleRange {});
+                    }
+                    Ok((start, end, s.2))
+                })
+                .collect()
+        };
+
+    config.distribution_schedule = convert(&config.distribution_schedule)?;
+    for stream in config.extra_rewards.iter_mut() {
+        stream.distribution_schedule = convert(&stream.distribution_schedule)?;
+    }
+    config.unbonding_period =
+        (Uint128::from(config.unbonding_period) * seconds_per_block).u128() as u64;
+    config.schedule_mode = ScheduleMode::Time;
+    store_config(deps.storage, &config)?;
+
+    for (owner, mut unbondings) in read_all_unbondings(deps.storage)? {
+        for unbonding in unbondings.iter_mut() {
+            unbonding.release_at = to_time(unbonding.release_at);
+        }
+        store_unbondings(deps.storage, &owner, &unbondings)?;
+    }
+
+    state.started_at = to_time(state.started_at);
+    state.last_distributed = env.block.time.seconds();
+    store_state(deps.storage, staking_token_version, &state)?;
+
+    // legacy states are halted, their points are still reported by queries
+    for token_version in 0..staking_token_version {
+        let mut state: StateV2 = read_state(deps.storage, token_version)?;
+        state.started_at = to_time(state.started_at);
+        state.last_distributed = to_time(state.last_distributed);
+        store_state(deps.storage, token_version, &state)?;
+    }
+
+    Ok(())
+}
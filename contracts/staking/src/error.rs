@@ -0,0 +1,74 @@
+use cosmwasm_std::{OverflowError, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("data should be given")]
+    DataShouldBeGiven {},
+
+    #[error("Cannot unbond more than bond amount")]
+    ExceedsBond {},
+
+    #[error("no bond to unbond")]
+    NoBondToUnbond {},
+
+    #[error("no matured unbonding to claim")]
+    NoMaturedUnbonding {},
+
+    #[error("no bond to kick")]
+    NoBondToKick {},
+
+    #[error("no pending reward to stake")]
+    NoPendingReward {},
+
+    #[error("no legacy bond to migrate")]
+    NoLegacyBond {},
+
+    #[error("legacy pair assets mismatch")]
+    LegacyPairMismatch {},
+
+    #[error("staking token has no minter")]
+    NoMinter {},
+
+    #[error("staking token is not a pair liquidity token")]
+    NotLiquidityToken {},
+
+    #[error("provided liquidity is less than the minimum")]
+    InsufficientLiquidity {},
+
+    #[error("not supported reply")]
+    InvalidReplyId {},
+
+    #[error("boost weight must not exceed 1")]
+    InvalidBoostWeight {},
+
+    #[error("reward stream already exists")]
+    RewardStreamExists {},
+
+    #[error("reward stream not found")]
+    RewardStreamNotFound {},
+
+    #[error("invalid distribution schedule: start must be less than end")]
+    InvalidScheduleRange {},
+
+    #[error("invalid distribution schedule: ranges must be ordered and non-overlapping")]
+    OverlappingSchedule {},
+
+    #[error("cannot modify elapsed distribution schedule")]
+    ElapsedScheduleModified {},
+
+    #[error("schedule is already time based")]
+    AlreadyTimeBased {},
+
+    #[error("seconds per block must be positive")]
+    InvalidSecondsPerBlock {},
+}
@@ -1,10 +1,11 @@
-use crate::contract::{execute, instantiate, query_staker_info};
+use crate::contract::{execute, instantiate, migrate, query_config, query_staker_info};
+use crate::error::ContractError;
 use crate::mock_querier::mock_dependencies;
 use crate::state::{read_config, read_state};
 use cosmwasm_std::testing::{mock_env, mock_info};
-use cosmwasm_std::{to_binary, Uint128};
+use cosmwasm_std::{to_binary, Decimal, Uint128};
 use cw20::Cw20ReceiveMsg;
-use pylon_token::staking::{Cw20HookMsg, ExecuteMsg, InstantiateMsg};
+use pylon_token::staking::{Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, ScheduleMode};
 
 #[test]
 fn migration_test() {
@@ -20,6 +21,7 @@ fn migration_test() {
             (13398386, 18304829, Uint128::from(250000000000000u128)),
             (18304829, 23211272, Uint128::from(250000000000000u128)),
         ],
+        schedule_mode: None,
     };
 
     let info = mock_info("addr0000", &[]);
@@ -74,3 +76,146 @@ fn migration_test() {
     let resp = query_staker_info(deps.as_ref(), "addr0000".to_string(), Some(8491943u64)).unwrap();
     println!("{:?}", resp);
 }
+
+#[test]
+fn migrate_time_schedule() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![
+            (12345, 12345 + 100, Uint128::from(1000000u128)),
+            (12345 + 100, 12345 + 200, Uint128::from(10000000u128)),
+        ],
+        schedule_mode: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("staking0000", &[]),
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "addr0000".to_string(),
+            amount: Uint128::from(100u128),
+            msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+        }),
+    )
+    .unwrap();
+
+    // 50 blocks of 5 seconds passed
+    let genesis = mock_env().block.time.seconds();
+    let mut env = mock_env();
+    env.block.height += 50;
+    env.block.time = env.block.time.plus_seconds(250);
+
+    let res = migrate(
+        deps.as_mut(),
+        env.clone(),
+        MigrateMsg::TimeSchedule {
+            seconds_per_block: Decimal::zero(),
+        },
+    );
+    match res {
+        Err(ContractError::InvalidSecondsPerBlock {}) => (),
+        _ => panic!("Must return invalid seconds per block error"),
+    }
+
+    migrate(
+        deps.as_mut(),
+        env.clone(),
+        MigrateMsg::TimeSchedule {
+            seconds_per_block: Decimal::from_ratio(5u64, 1u64),
+        },
+    )
+    .unwrap();
+
+    let config = query_config(deps.as_ref()).unwrap();
+    assert_eq!(config.schedule_mode, ScheduleMode::Time);
+    assert_eq!(
+        config.distribution_schedule,
+        vec![
+            (genesis, genesis + 500, Uint128::from(1000000u128)),
+            (genesis + 500, genesis + 1000, Uint128::from(10000000u128)),
+        ]
+    );
+
+    // rewards were settled by height before switching
+    let state = read_state(deps.as_ref().storage, 0).unwrap();
+    assert_eq!(state.started_at, genesis);
+    assert_eq!(state.last_distributed, genesis + 250);
+    assert_eq!(
+        state.global_reward_index,
+        Decimal::from_ratio(5000u64, 1u64)
+    );
+
+    assert_eq!(
+        query_staker_info(deps.as_ref(), "addr0000".to_string(), Some(genesis + 500))
+            .unwrap()
+            .pending_reward,
+        Uint128::from(1000000u128)
+    );
+    assert_eq!(
+        query_staker_info(deps.as_ref(), "addr0000".to_string(), Some(genesis + 1000))
+            .unwrap()
+            .pending_reward,
+        Uint128::from(11000000u128)
+    );
+
+    let res = migrate(
+        deps.as_mut(),
+        env.clone(),
+        MigrateMsg::TimeSchedule {
+            seconds_per_block: Decimal::from_ratio(5u64, 1u64),
+        },
+    );
+    match res {
+        Err(ContractError::AlreadyTimeBased {}) => (),
+        _ => panic!("Must return already time based error"),
+    }
+
+    // halted legacy states are converted as well
+    let mut deps = mock_dependencies(&[]);
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![(12345, 12345 + 100, Uint128::from(1000000u128))],
+        schedule_mode: None,
+    };
+    let _res = instantiate(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    let mut staked = mock_env();
+    staked.block.height += 20;
+    execute(
+        deps.as_mut(),
+        staked,
+        mock_info("gov0000", &[]),
+        ExecuteMsg::MigrateStaking {
+            new_staking_contract: "staking0001".to_string(),
+        },
+    )
+    .unwrap();
+
+    migrate(
+        deps.as_mut(),
+        env,
+        MigrateMsg::TimeSchedule {
+            seconds_per_block: Decimal::from_ratio(5u64, 1u64),
+        },
+    )
+    .unwrap();
+
+    let state = read_state(deps.as_ref().storage, 0).unwrap();
+    assert!(state.halted);
+    assert_eq!(state.started_at, genesis);
+    assert_eq!(state.last_distributed, genesis + 100);
+
+    let state = read_state(deps.as_ref().storage, 1).unwrap();
+    assert_eq!(state.started_at, genesis + 100);
+    assert_eq!(state.last_distributed, genesis + 250);
+}
@@ -1,12 +1,17 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    from_binary, from_slice, to_binary, Coin, ContractResult, Empty, OwnedDeps, Querier,
-    QuerierResult, QueryRequest, SystemError, SystemResult, WasmQuery,
+    from_binary, from_slice, to_binary, Binary, Coin, ContractResult, Decimal, Empty, OwnedDeps,
+    Querier, QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
 };
-use cw20::MinterResponse;
+use cw20::{BalanceResponse as Cw20BalanceResponse, MinterResponse};
+use pylon_token::gov_resp::{
+    StakerResponse as GovStakerResponse, StateResponse as GovStateResponse,
+};
+use terraswap::asset::{AssetInfo, PairInfo};
 
 /// mock_dependencies is a drop-in replacement for cosmwasm_std::testing::mock_dependencies
 /// this uses our CustomQuerier.
@@ -26,11 +31,16 @@ pub fn mock_dependencies(
 pub struct WasmMockQuerier {
     base: MockQuerier<Empty>,
     minter_querier: MinterQuerier,
+    token_querier: TokenQuerier,
+    pair_querier: PairQuerier,
+    gov_querier: GovQuerier,
 }
 
 #[derive(Clone, Default)]
 pub struct MinterQuerier {
     minter_addr: String,
+    // minter of each token, falls back to minter_addr
+    minters: HashMap<String, String>,
 }
 
 impl MinterQuerier {
@@ -38,6 +48,78 @@ impl MinterQuerier {
     pub fn new(minter: String) -> Self {
         MinterQuerier {
             minter_addr: minter,
+            minters: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct TokenQuerier {
+    // this lets us iterate over all pairs that match the first string
+    balances: HashMap<String, HashMap<String, Uint128>>,
+}
+
+impl TokenQuerier {
+    pub fn new(balances: &[(&String, &[(&String, &Uint128)])]) -> Self {
+        TokenQuerier {
+            balances: balances_to_map(balances),
+        }
+    }
+}
+
+pub(crate) fn balances_to_map(
+    balances: &[(&String, &[(&String, &Uint128)])],
+) -> HashMap<String, HashMap<String, Uint128>> {
+    let mut balances_map: HashMap<String, HashMap<String, Uint128>> = HashMap::new();
+    for (contract_addr, balances) in balances.iter() {
+        let mut contract_balances_map: HashMap<String, Uint128> = HashMap::new();
+        for (addr, balance) in balances.iter() {
+            contract_balances_map.insert(addr.to_string(), **balance);
+        }
+
+        balances_map.insert(contract_addr.to_string(), contract_balances_map);
+    }
+    balances_map
+}
+
+#[derive(Clone, Default)]
+pub struct PairQuerier {
+    pairs: HashMap<String, PairInfo>,
+}
+
+impl PairQuerier {
+    pub fn new(pairs: &[(&String, &[AssetInfo; 2], &String)]) -> Self {
+        let mut pairs_map: HashMap<String, PairInfo> = HashMap::new();
+        for (contract_addr, asset_infos, liquidity_token) in pairs.iter() {
+            pairs_map.insert(
+                contract_addr.to_string(),
+                PairInfo {
+                    asset_infos: (*asset_infos).clone(),
+                    contract_addr: contract_addr.to_string(),
+                    liquidity_token: liquidity_token.to_string(),
+                },
+            );
+        }
+
+        PairQuerier { pairs: pairs_map }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct GovQuerier {
+    // staked balance of each gov staker, gov queries fail if not configured
+    balances: Option<HashMap<String, Uint128>>,
+}
+
+impl GovQuerier {
+    pub fn new(balances: &[(&String, &Uint128)]) -> Self {
+        let mut balances_map: HashMap<String, Uint128> = HashMap::new();
+        for (staker, balance) in balances.iter() {
+            balances_map.insert(staker.to_string(), **balance);
+        }
+
+        GovQuerier {
+            balances: Some(balances_map),
         }
     }
 }
@@ -46,6 +128,10 @@ impl MinterQuerier {
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     Minter {},
+    Balance { address: String },
+    Pair {},
+    State {},
+    Staker { address: String },
 }
 
 impl Querier for WasmMockQuerier {
@@ -67,16 +153,70 @@ impl Querier for WasmMockQuerier {
 impl WasmMockQuerier {
     pub fn handle_query(&self, request: &QueryRequest<Empty>) -> QuerierResult {
         match &request {
-            QueryRequest::Wasm(WasmQuery::Smart {
-                contract_addr: _,
-                msg,
-            }) => match from_binary(msg) {
+            QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => match from_binary(msg) {
                 Ok(QueryMsg::Minter {}) => {
+                    let minter = match self.minter_querier.minters.get(contract_addr) {
+                        Some(v) => v.clone(),
+                        None => self.minter_querier.minter_addr.clone(),
+                    };
+
                     SystemResult::Ok(ContractResult::from(to_binary(&MinterResponse {
-                        minter: self.minter_querier.minter_addr.clone(),
+                        minter,
                         cap: None,
                     })))
                 }
+                Ok(QueryMsg::Balance { address }) => {
+                    let balance = self
+                        .token_querier
+                        .balances
+                        .get(contract_addr)
+                        .and_then(|balances| balances.get(&address))
+                        .cloned()
+                        .unwrap_or_default();
+
+                    SystemResult::Ok(ContractResult::from(to_binary(&Cw20BalanceResponse {
+                        balance,
+                    })))
+                }
+                Ok(QueryMsg::Pair {}) => match self.pair_querier.pairs.get(contract_addr) {
+                    Some(v) => SystemResult::Ok(ContractResult::from(to_binary(v))),
+                    None => SystemResult::Err(SystemError::InvalidRequest {
+                        error: "No pair info exists".to_string(),
+                        request: msg.as_slice().into(),
+                    }),
+                },
+                Ok(QueryMsg::State {}) => {
+                    let balances = match &self.gov_querier.balances {
+                        Some(v) => v,
+                        None => return no_gov_stakers(msg),
+                    };
+                    let total_balance: Uint128 = balances.values().sum();
+
+                    SystemResult::Ok(ContractResult::from(to_binary(&GovStateResponse {
+                        poll_count: 0,
+                        total_share: total_balance,
+                        total_deposit: Uint128::zero(),
+                        total_airdrop_count: 0,
+                        airdrop_update_candidates: vec![],
+                        airdrop_update_candidate_count: 0,
+                        total_balance,
+                        balance_per_share: Decimal::one(),
+                    })))
+                }
+                Ok(QueryMsg::Staker { address }) => {
+                    let balances = match &self.gov_querier.balances {
+                        Some(v) => v,
+                        None => return no_gov_stakers(msg),
+                    };
+                    let balance = balances.get(&address).cloned().unwrap_or_default();
+
+                    SystemResult::Ok(ContractResult::from(to_binary(&GovStakerResponse {
+                        balance,
+                        share: balance,
+                        claimable_airdrop: vec![],
+                        locked_balance: vec![],
+                    })))
+                }
                 _ => panic!("query not mocked"),
             },
             _ => self.base.handle_query(request),
@@ -84,11 +224,21 @@ impl WasmMockQuerier {
     }
 }
 
+fn no_gov_stakers(msg: &Binary) -> QuerierResult {
+    SystemResult::Err(SystemError::InvalidRequest {
+        error: "No gov stakers configured".to_string(),
+        request: msg.as_slice().into(),
+    })
+}
+
 impl WasmMockQuerier {
     pub fn new(base: MockQuerier<Empty>) -> Self {
         WasmMockQuerier {
             base,
             minter_querier: MinterQuerier::default(),
+            token_querier: TokenQuerier::default(),
+            pair_querier: PairQuerier::default(),
+            gov_querier: GovQuerier::default(),
         }
     }
 
@@ -96,4 +246,28 @@ impl WasmMockQuerier {
     pub fn with_mine_minter(&mut self, minter: String) {
         self.minter_querier = MinterQuerier::new(minter);
     }
+
+    // configure the minter of each token
+    pub fn with_minters(&mut self, minters: &[(&String, &String)]) {
+        for (token, minter) in minters.iter() {
+            self.minter_querier
+                .minters
+                .insert(token.to_string(), minter.to_string());
+        }
+    }
+
+    // configure the token balance mock querier
+    pub fn with_token_balances(&mut self, balances: &[(&String, &[(&String, &Uint128)])]) {
+        self.token_querier = TokenQuerier::new(balances);
+    }
+
+    // configure the terraswap pair
+    pub fn with_terraswap_pairs(&mut self, pairs: &[(&String, &[AssetInfo; 2], &String)]) {
+        self.pair_querier = PairQuerier::new(pairs);
+    }
+
+    // configure the gov staked balances
+    pub fn with_gov_stakers(&mut self, balances: &[(&String, &Uint128)]) {
+        self.gov_querier = GovQuerier::new(balances);
+    }
 }
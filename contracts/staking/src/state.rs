@@ -1,13 +1,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::{CanonicalAddr, Decimal, StdResult, Storage, Uint128};
+use cosmwasm_std::{CanonicalAddr, Decimal, Env, Order, StdResult, Storage, Uint128};
 use cosmwasm_storage::{singleton, singleton_read, Bucket, ReadonlyBucket};
+use pylon_token::common::OrderBy;
+use pylon_token::staking::ScheduleMode;
+use pylon_utils::range::{calc_range_end_addr, calc_range_start_addr};
+use terraswap::asset::Asset;
 
 static KEY_CONFIG: &[u8] = b"config";
+static KEY_MIGRATE_BOND: &[u8] = b"migrate_bond";
 
 static PREFIX_STATE: &[u8] = b"state";
 static PREFIX_REWARD: &[u8] = b"reward";
+static PREFIX_UNBONDING: &[u8] = b"unbonding";
+
+const MAX_QUERY_LIMIT: u32 = 30;
+const DEFAULT_QUERY_LIMIT: u32 = 10;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ConfigV1 {
@@ -22,6 +31,30 @@ pub struct ConfigV2 {
     pub pylon_token: CanonicalAddr,
     pub staking_token: Vec<CanonicalAddr>, // index = token_version
     pub distribution_schedule: Vec<(u64, u64, Uint128)>,
+    #[serde(default)]
+    pub schedule_mode: ScheduleMode,
+    #[serde(default)]
+    pub unbonding_period: u64, // zero releases unbonded tokens immediately
+    #[serde(default)]
+    pub boost_weight: Decimal, // share of emissions weighted by gov stake, zero disables boost
+    #[serde(default)]
+    pub extra_rewards: Vec<RewardStream>, // distributed alongside pylon_token
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RewardStream {
+    pub token: CanonicalAddr,
+    pub distribution_schedule: Vec<(u64, u64, Uint128)>,
+}
+
+impl ConfigV2 {
+    /// returns current point of the distribution schedule
+    pub fn schedule_now(&self, env: &Env) -> u64 {
+        match self.schedule_mode {
+            ScheduleMode::Height => env.block.height,
+            ScheduleMode::Time => env.block.time.seconds(),
+        }
+    }
 }
 
 pub fn store_config(storage: &mut dyn Storage, config: &ConfigV2) -> StdResult<()> {
@@ -46,6 +79,33 @@ pub struct StateV2 {
     pub last_distributed: u64,
     pub total_bond_amount: Uint128,
     pub global_reward_index: Decimal,
+    #[serde(default)]
+    pub extra_reward_indices: Vec<(CanonicalAddr, Decimal)>,
+    #[serde(default)]
+    pub total_working_amount: Option<Uint128>,
+}
+
+impl StateV2 {
+    /// returns sum of working amounts of all stakers,
+    /// which equals to total bond amount until any boost is applied
+    pub fn working_amount(&self) -> Uint128 {
+        self.total_working_amount.unwrap_or(self.total_bond_amount)
+    }
+
+    /// returns global reward index of the given extra reward token,
+    /// registering it from zero if it has not been distributed yet
+    pub fn extra_reward_index_mut(&mut self, token: &CanonicalAddr) -> &mut Decimal {
+        let position = match self.extra_reward_indices.iter().position(|x| &x.0 == token) {
+            Some(position) => position,
+            None => {
+                self.extra_reward_indices
+                    .push((token.clone(), Decimal::zero()));
+                self.extra_reward_indices.len() - 1
+            }
+        };
+
+        &mut self.extra_reward_indices[position].1
+    }
 }
 
 pub fn store_state(
@@ -66,6 +126,23 @@ pub struct StakerInfoV1 {
     pub bond_amount: Uint128,
     pub pending_reward: Uint128,
     pub staking_token_version: Option<u64>,
+    #[serde(default)]
+    pub extra_rewards: Vec<StakerReward>,
+    #[serde(default)]
+    pub working_amount: Option<Uint128>,
+}
+
+impl From<StakerInfoV1> for StakerInfoV2 {
+    fn from(staker_info: StakerInfoV1) -> Self {
+        StakerInfoV2 {
+            reward_index: staker_info.reward_index,
+            bond_amount: staker_info.bond_amount,
+            pending_reward: staker_info.pending_reward,
+            staking_token_version: staker_info.staking_token_version.unwrap_or_default(),
+            extra_rewards: staker_info.extra_rewards,
+            working_amount: staker_info.working_amount,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -74,6 +151,50 @@ pub struct StakerInfoV2 {
     pub bond_amount: Uint128,
     pub pending_reward: Uint128,
     pub staking_token_version: u64,
+    #[serde(default)]
+    pub extra_rewards: Vec<StakerReward>,
+    #[serde(default)]
+    pub working_amount: Option<Uint128>, // bond amount until any boost is applied
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakerReward {
+    pub token: CanonicalAddr,
+    pub reward_index: Decimal,
+    pub pending_reward: Uint128,
+}
+
+impl StakerInfoV2 {
+    /// returns bond amount weighted by gov stake, which rewards are distributed by
+    pub fn working_amount(&self) -> Uint128 {
+        self.working_amount.unwrap_or(self.bond_amount)
+    }
+
+    /// returns reward info of the given extra reward token,
+    /// registering it from zero if the staker has not received it yet
+    pub fn extra_reward_mut(&mut self, token: &CanonicalAddr) -> &mut StakerReward {
+        let position = match self.extra_rewards.iter().position(|x| &x.token == token) {
+            Some(position) => position,
+            None => {
+                self.extra_rewards.push(StakerReward {
+                    token: token.clone(),
+                    reward_index: Decimal::zero(),
+                    pending_reward: Uint128::zero(),
+                });
+                self.extra_rewards.len() - 1
+            }
+        };
+
+        &mut self.extra_rewards[position]
+    }
+
+    pub fn has_pending_reward(&self) -> bool {
+        !self.pending_reward.is_zero()
+            || self
+                .extra_rewards
+                .iter()
+                .any(|x| !x.pending_reward.is_zero())
+    }
 }
 
 /// returns return staker_info of the given owner
@@ -96,17 +217,121 @@ pub fn read_staker_info(storage: &dyn Storage, owner: &CanonicalAddr) -> StdResu
     let config = read_config(storage)?;
 
     match ReadonlyBucket::<StakerInfoV1>::new(storage, PREFIX_REWARD).may_load(owner.as_slice())? {
-        Some(staker_info) => Ok(StakerInfoV2 {
-            reward_index: staker_info.reward_index,
-            bond_amount: staker_info.bond_amount,
-            pending_reward: staker_info.pending_reward,
-            staking_token_version: staker_info.staking_token_version.unwrap_or_default(),
-        }),
+        Some(staker_info) => Ok(staker_info.into()),
         None => Ok(StakerInfoV2 {
             reward_index: Decimal::zero(),
             bond_amount: Uint128::zero(),
             pending_reward: Uint128::zero(),
             staking_token_version: (config.staking_token.len() - 1) as u64,
+            extra_rewards: vec![],
+            working_amount: None,
         }),
     }
 }
+
+/// staker_infos of a page and the last scanned key
+pub type StakerInfoPage = (Vec<(CanonicalAddr, StakerInfoV2)>, Option<CanonicalAddr>);
+
+/// returns a page of staker_infos, optionally only of the given token version,
+/// and the last scanned key. At most limit entries are scanned, so the page
+/// can be shorter than the limit if filtered by token version
+pub fn read_staker_infos(
+    storage: &dyn Storage,
+    start_after: Option<CanonicalAddr>,
+    limit: Option<u32>,
+    order_by: Option<OrderBy>,
+    token_version: Option<u64>,
+) -> StdResult<StakerInfoPage> {
+    let (start, end, order_by) = match order_by {
+        Some(OrderBy::Asc) => (calc_range_start_addr(start_after), None, OrderBy::Asc),
+        _ => (None, calc_range_end_addr(start_after), OrderBy::Desc),
+    };
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+    let mut staker_infos = vec![];
+    let mut last_key = None;
+    for item in ReadonlyBucket::<StakerInfoV1>::new(storage, PREFIX_REWARD)
+        .range(start.as_deref(), end.as_deref(), order_by.into())
+        .take(limit)
+    {
+        let (k, v) = item?;
+        let staker_raw = CanonicalAddr::from(k);
+        let staker_info: StakerInfoV2 = v.into();
+
+        last_key = Some(staker_raw.clone());
+        if token_version.is_none() || token_version == Some(staker_info.staking_token_version) {
+            staker_infos.push((staker_raw, staker_info));
+        }
+    }
+
+    Ok((staker_infos, last_key))
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Unbonding {
+    pub staking_token_version: u64,
+    pub amount: Uint128,
+    pub release_at: u64,
+}
+
+pub fn store_unbondings(
+    storage: &mut dyn Storage,
+    owner: &CanonicalAddr,
+    unbondings: &[Unbonding],
+) -> StdResult<()> {
+    let mut bucket = Bucket::<Vec<Unbonding>>::new(storage, PREFIX_UNBONDING);
+    if unbondings.is_empty() {
+        bucket.remove(owner.as_slice());
+        Ok(())
+    } else {
+        bucket.save(owner.as_slice(), &unbondings.to_vec())
+    }
+}
+
+/// returns unbonding queue of the given owner
+pub fn read_unbondings(storage: &dyn Storage, owner: &CanonicalAddr) -> StdResult<Vec<Unbonding>> {
+    Ok(
+        ReadonlyBucket::<Vec<Unbonding>>::new(storage, PREFIX_UNBONDING)
+            .may_load(owner.as_slice())?
+            .unwrap_or_default(),
+    )
+}
+
+/// returns unbonding queues of all owners
+pub fn read_all_unbondings(
+    storage: &dyn Storage,
+) -> StdResult<Vec<(CanonicalAddr, Vec<Unbonding>)>> {
+    ReadonlyBucket::<Vec<Unbonding>>::new(storage, PREFIX_UNBONDING)
+        .range(None, None, Order::Ascending)
+        .map(|item| -> StdResult<(CanonicalAddr, Vec<Unbonding>)> {
+            let (k, v) = item?;
+            Ok((CanonicalAddr::from(k), v))
+        })
+        .collect()
+}
+
+/// in-flight MigrateBond of a staker, kept between the submessage replies
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateBondContext {
+    pub staker: CanonicalAddr,
+    pub pair: CanonicalAddr,  // pair of the latest staking token
+    pub balances: Vec<Asset>, // contract balances of the pair assets before withdrawal
+    pub lp_balance: Uint128,  // contract balance of the latest staking token before provision
+    pub slippage_tolerance: Option<Decimal>,
+    pub min_lp_amount: Uint128,
+}
+
+pub fn store_migrate_bond_context(
+    storage: &mut dyn Storage,
+    context: &MigrateBondContext,
+) -> StdResult<()> {
+    singleton(storage, KEY_MIGRATE_BOND).save(context)
+}
+
+pub fn read_migrate_bond_context(storage: &dyn Storage) -> StdResult<MigrateBondContext> {
+    singleton_read(storage, KEY_MIGRATE_BOND).load()
+}
+
+pub fn remove_migrate_bond_context(storage: &mut dyn Storage) {
+    singleton::<MigrateBondContext>(storage, KEY_MIGRATE_BOND).remove()
+}
@@ -1,14 +1,21 @@
-use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    from_binary, to_binary, CosmosMsg, Decimal, StdError, SubMsg, Uint128, WasmMsg,
+    attr, from_binary, to_binary, ContractResult, CosmosMsg, Decimal, Reply, StdError, SubMsg,
+    SubMsgExecutionResponse, Uint128, WasmMsg,
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use pylon_token::common::OrderBy;
+use pylon_token::gov_msg::Cw20HookMsg as GovCw20HookMsg;
 use pylon_token::staking::{
-    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg, StakerInfoResponse,
-    StateResponse,
+    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg, RewardStreamResponse,
+    ScheduleMode, StakerInfoResponse, StakerInfosResponse, StateResponse, StatsResponse,
+    UnbondingResponse, UnbondingsResponse,
 };
+use terraswap::asset::{Asset, AssetInfo};
+use terraswap::pair::{Cw20HookMsg as TerraswapCw20HookMsg, ExecuteMsg as TerraswapExecuteMsg};
 
-use crate::contract::{execute, instantiate, query};
+use crate::contract::{execute, instantiate, query, reply};
+use crate::error::ContractError;
 use crate::mock_querier::mock_dependencies;
 
 #[test]
@@ -20,6 +27,7 @@ fn proper_initialization() {
         pylon_token: "reward0000".to_string(),
         staking_token: "staking0000".to_string(),
         distribution_schedule: vec![(100, 200, Uint128::from(1000000u128))],
+        schedule_mode: None,
     };
 
     let info = mock_info("addr0000", &[]);
@@ -36,6 +44,10 @@ fn proper_initialization() {
             pylon_token: "reward0000".to_string(),
             staking_token: vec!["staking0000".to_string()],
             distribution_schedule: vec![(100, 200, Uint128::from(1000000u128))],
+            schedule_mode: ScheduleMode::Height,
+            unbonding_period: 0,
+            boost_weight: Decimal::zero(),
+            extra_rewards: vec![],
         }
     );
 
@@ -56,6 +68,7 @@ fn proper_initialization() {
             started_at: 12345,
             last_distributed: 12345,
             total_bond_amount: Uint128::zero(),
+            total_working_amount: Uint128::zero(),
             global_reward_index: Decimal::zero(),
         }
     );
@@ -73,6 +86,7 @@ fn test_bond_tokens() {
             (12345, 12345 + 100, Uint128::from(1000000u128)),
             (12345 + 100, 12345 + 200, Uint128::from(10000000u128)),
         ],
+        schedule_mode: None,
     };
 
     let info = mock_info("addr0000", &[]);
@@ -107,6 +121,8 @@ fn test_bond_tokens() {
             reward_index: Decimal::zero(),
             pending_reward: Uint128::zero(),
             bond_amount: Uint128::from(100u128),
+            working_amount: Uint128::from(100u128),
+            pending_rewards: vec![("reward0000".to_string(), Uint128::zero())],
         }
     );
 
@@ -126,6 +142,7 @@ fn test_bond_tokens() {
         StateResponse {
             halted: false,
             total_bond_amount: Uint128::from(100u128),
+            total_working_amount: Uint128::from(100u128),
             global_reward_index: Decimal::zero(),
             last_distributed: 12345,
             started_at: 12345
@@ -161,6 +178,8 @@ fn test_bond_tokens() {
             reward_index: Decimal::from_ratio(1000u128, 1u128),
             pending_reward: Uint128::from(100000u128),
             bond_amount: Uint128::from(200u128),
+            working_amount: Uint128::from(200u128),
+            pending_rewards: vec![("reward0000".to_string(), Uint128::from(100000u128))],
         }
     );
 
@@ -180,6 +199,7 @@ fn test_bond_tokens() {
         StateResponse {
             halted: false,
             total_bond_amount: Uint128::from(200u128),
+            total_working_amount: Uint128::from(200u128),
             global_reward_index: Decimal::from_ratio(1000u128, 1u128),
             last_distributed: 12345 + 10,
             started_at: 12345
@@ -196,7 +216,7 @@ fn test_bond_tokens() {
     let info = mock_info("staking0001", &[]);
     let res = execute(deps.as_mut(), mock_env(), info, msg);
     match res {
-        Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "unauthorized"),
+        Err(ContractError::Unauthorized {}) => (),
         _ => panic!("Must return unauthorized error"),
     }
 }
@@ -213,6 +233,7 @@ fn test_unbond() {
             (12345, 12345 + 100, Uint128::from(1000000u128)),
             (12345 + 100, 12345 + 200, Uint128::from(10000000u128)),
         ],
+        schedule_mode: None,
     };
 
     let info = mock_info("addr0000", &[]);
@@ -235,10 +256,8 @@ fn test_unbond() {
     let info = mock_info("addr0000", &[]);
     let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
     match res {
-        StdError::GenericErr { msg, .. } => {
-            assert_eq!(msg, "Cannot unbond more than bond amount");
-        }
-        _ => panic!("Must return generic error"),
+        ContractError::ExceedsBond {} => (),
+        _ => panic!("Must return exceeds bond error"),
     };
 
     // normal unbond
@@ -274,6 +293,7 @@ fn test_compute_reward() {
             (12345, 12345 + 100, Uint128::from(1000000u128)),
             (12345 + 100, 12345 + 200, Uint128::from(10000000u128)),
         ],
+        schedule_mode: None,
     };
 
     let info = mock_info("addr0000", &[]);
@@ -320,6 +340,8 @@ fn test_compute_reward() {
             reward_index: Decimal::from_ratio(10000u128, 1u128),
             pending_reward: Uint128::from(1000000u128),
             bond_amount: Uint128::from(200u128),
+            working_amount: Uint128::from(200u128),
+            pending_rewards: vec![("reward0000".to_string(), Uint128::from(1000000u128))],
         }
     );
 
@@ -352,6 +374,8 @@ fn test_compute_reward() {
             reward_index: Decimal::from_ratio(15000u64, 1u64),
             pending_reward: Uint128::from(2000000u128),
             bond_amount: Uint128::from(100u128),
+            working_amount: Uint128::from(100u128),
+            pending_rewards: vec![("reward0000".to_string(), Uint128::from(2000000u128))],
         }
     );
 
@@ -375,6 +399,8 @@ fn test_compute_reward() {
             reward_index: Decimal::from_ratio(25000u64, 1u64),
             pending_reward: Uint128::from(3000000u128),
             bond_amount: Uint128::from(100u128),
+            working_amount: Uint128::from(100u128),
+            pending_rewards: vec![("reward0000".to_string(), Uint128::from(3000000u128))],
         }
     );
 }
@@ -391,6 +417,7 @@ fn test_withdraw() {
             (12345, 12345 + 100, Uint128::from(1000000u128)),
             (12345 + 100, 12345 + 200, Uint128::from(10000000u128)),
         ],
+        schedule_mode: None,
     };
 
     let info = mock_info("addr0000", &[]);
@@ -411,7 +438,7 @@ fn test_withdraw() {
     env.block.height += 100;
     let info = mock_info("addr0000", &[]);
 
-    let msg = ExecuteMsg::Withdraw {};
+    let msg = ExecuteMsg::Withdraw { recipient: None };
     let res = execute(deps.as_mut(), env, info, msg).unwrap();
 
     assert_eq!(
@@ -427,3 +454,1875 @@ fn test_withdraw() {
         }))]
     );
 }
+
+#[test]
+fn test_update_config() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![
+            (12345, 12345 + 100, Uint128::from(1000000u128)),
+            (12345 + 100, 12345 + 200, Uint128::from(10000000u128)),
+        ],
+        schedule_mode: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // bond 100 tokens
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "addr0000".to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+    });
+    let info = mock_info("staking0000", &[]);
+    let mut env = mock_env();
+    let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // 50 blocks passed
+    env.block.height += 50;
+
+    // only governance can update
+    let msg = ExecuteMsg::UpdateConfig {
+        distribution_schedule: None,
+        unbonding_period: None,
+        boost_weight: None,
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("addr0000", &[]), msg);
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("Must return unauthorized error"),
+    }
+
+    // empty range
+    let msg = ExecuteMsg::UpdateConfig {
+        distribution_schedule: Some(vec![
+            (12345, 12345 + 100, Uint128::from(1000000u128)),
+            (12345 + 200, 12345 + 200, Uint128::from(10000000u128)),
+        ]),
+        unbonding_period: None,
+        boost_weight: None,
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg);
+    match res {
+        Err(ContractError::InvalidScheduleRange {}) => (),
+        _ => panic!("Must return invalid schedule range error"),
+    }
+
+    // overlapping ranges
+    let msg = ExecuteMsg::UpdateConfig {
+        distribution_schedule: Some(vec![
+            (12345, 12345 + 100, Uint128::from(1000000u128)),
+            (12345 + 99, 12345 + 200, Uint128::from(10000000u128)),
+        ]),
+        unbonding_period: None,
+        boost_weight: None,
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg);
+    match res {
+        Err(ContractError::OverlappingSchedule {}) => (),
+        _ => panic!("Must return overlapping schedule error"),
+    }
+
+    // running range cannot be modified
+    let msg = ExecuteMsg::UpdateConfig {
+        distribution_schedule: Some(vec![
+            (12345, 12345 + 100, Uint128::from(2000000u128)),
+            (12345 + 100, 12345 + 200, Uint128::from(10000000u128)),
+        ]),
+        unbonding_period: None,
+        boost_weight: None,
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg);
+    match res {
+        Err(ContractError::ElapsedScheduleModified {}) => (),
+        _ => panic!("Must return elapsed schedule modified error"),
+    }
+
+    // range starting in the past cannot be added
+    let msg = ExecuteMsg::UpdateConfig {
+        distribution_schedule: Some(vec![
+            (12345 - 10, 12345, Uint128::from(1000000u128)),
+            (12345, 12345 + 100, Uint128::from(1000000u128)),
+            (12345 + 100, 12345 + 200, Uint128::from(10000000u128)),
+        ]),
+        unbonding_period: None,
+        boost_weight: None,
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg);
+    match res {
+        Err(ContractError::ElapsedScheduleModified {}) => (),
+        _ => panic!("Must return elapsed schedule modified error"),
+    }
+
+    // double the upcoming emission and append a new range
+    let msg = ExecuteMsg::UpdateConfig {
+        distribution_schedule: Some(vec![
+            (12345, 12345 + 100, Uint128::from(1000000u128)),
+            (12345 + 100, 12345 + 200, Uint128::from(20000000u128)),
+            (12345 + 300, 12345 + 400, Uint128::from(5000000u128)),
+        ]),
+        unbonding_period: None,
+        boost_weight: None,
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg).unwrap();
+    assert_eq!(res.attributes, vec![attr("action", "update_config")]);
+
+    let config: ConfigResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(
+        config.distribution_schedule,
+        vec![
+            (12345, 12345 + 100, Uint128::from(1000000u128)),
+            (12345 + 100, 12345 + 200, Uint128::from(20000000u128)),
+            (12345 + 300, 12345 + 400, Uint128::from(5000000u128)),
+        ]
+    );
+
+    // rewards were settled up to the update
+    let state: StateResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::State {
+                token_version: None,
+                block_height: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(state.last_distributed, 12345 + 50);
+    assert_eq!(
+        state.global_reward_index,
+        Decimal::from_ratio(5000u64, 1u64)
+    );
+
+    // 1,000,000 from the first range + half of 20,000,000 from the second
+    assert_eq!(
+        from_binary::<StakerInfoResponse>(
+            &query(
+                deps.as_ref(),
+                env,
+                QueryMsg::StakerInfo {
+                    staker: "addr0000".to_string(),
+                    block_height: Some(12345 + 150),
+                },
+            )
+            .unwrap()
+        )
+        .unwrap()
+        .pending_reward,
+        Uint128::from(11000000u128)
+    );
+}
+
+#[test]
+fn test_extra_reward_stream() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![
+            (12345, 12345 + 100, Uint128::from(1000000u128)),
+            (12345 + 100, 12345 + 200, Uint128::from(10000000u128)),
+        ],
+        schedule_mode: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // bond 100 tokens
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "addr0000".to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+    });
+    let info = mock_info("staking0000", &[]);
+    let mut env = mock_env();
+    let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // 50 blocks passed
+    env.block.height += 50;
+
+    // only governance can add
+    let msg = ExecuteMsg::AddRewardStream {
+        token: "partner0000".to_string(),
+        distribution_schedule: vec![(12345 + 50, 12345 + 150, Uint128::from(1000u128))],
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("addr0000", &[]), msg);
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("Must return unauthorized error"),
+    }
+
+    // pylon token is already distributed
+    let msg = ExecuteMsg::AddRewardStream {
+        token: "reward0000".to_string(),
+        distribution_schedule: vec![(12345 + 50, 12345 + 150, Uint128::from(1000u128))],
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg);
+    match res {
+        Err(ContractError::RewardStreamExists {}) => (),
+        _ => panic!("Must return reward stream exists error"),
+    }
+
+    // cannot distribute for the past
+    let msg = ExecuteMsg::AddRewardStream {
+        token: "partner0000".to_string(),
+        distribution_schedule: vec![(12345, 12345 + 150, Uint128::from(1000u128))],
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg);
+    match res {
+        Err(ContractError::ElapsedScheduleModified {}) => (),
+        _ => panic!("Must return elapsed schedule modified error"),
+    }
+
+    let msg = ExecuteMsg::AddRewardStream {
+        token: "partner0000".to_string(),
+        distribution_schedule: vec![(12345 + 50, 12345 + 150, Uint128::from(1000u128))],
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "add_reward_stream"),
+            attr("token", "partner0000"),
+        ]
+    );
+
+    let config: ConfigResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(
+        config.extra_rewards,
+        vec![RewardStreamResponse {
+            token: "partner0000".to_string(),
+            distribution_schedule: vec![(12345 + 50, 12345 + 150, Uint128::from(1000u128))],
+        }]
+    );
+
+    // 50 blocks passed
+    // 500,000 pylon and 500 partner rewards distributed
+    env.block.height += 50;
+    assert_eq!(
+        from_binary::<StakerInfoResponse>(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::StakerInfo {
+                    staker: "addr0000".to_string(),
+                    block_height: Some(env.block.height),
+                },
+            )
+            .unwrap()
+        )
+        .unwrap()
+        .pending_rewards,
+        vec![
+            ("reward0000".to_string(), Uint128::from(1000000u128)),
+            ("partner0000".to_string(), Uint128::from(500u128)),
+        ]
+    );
+
+    // another staker joins
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "addr0001".to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+    });
+    let _res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("staking0000", &[]),
+        msg,
+    )
+    .unwrap();
+
+    // 50 blocks passed
+    // 5,000,000 pylon and 500 partner rewards distributed, split in half
+    env.block.height += 50;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::Withdraw { recipient: None },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "reward0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "addr0000".to_string(),
+                    amount: Uint128::from(3500000u128),
+                })
+                .unwrap(),
+                funds: vec![],
+            })),
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "partner0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "addr0000".to_string(),
+                    amount: Uint128::from(750u128),
+                })
+                .unwrap(),
+                funds: vec![],
+            })),
+        ]
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::Withdraw { recipient: None },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "reward0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "addr0001".to_string(),
+                    amount: Uint128::from(2500000u128),
+                })
+                .unwrap(),
+                funds: vec![],
+            })),
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "partner0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "addr0001".to_string(),
+                    amount: Uint128::from(250u128),
+                })
+                .unwrap(),
+                funds: vec![],
+            })),
+        ]
+    );
+
+    // everything was paid out
+    assert_eq!(
+        from_binary::<StakerInfoResponse>(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::StakerInfo {
+                    staker: "addr0000".to_string(),
+                    block_height: None,
+                },
+            )
+            .unwrap()
+        )
+        .unwrap()
+        .pending_rewards,
+        vec![
+            ("reward0000".to_string(), Uint128::zero()),
+            ("partner0000".to_string(), Uint128::zero()),
+        ]
+    );
+
+    // only governance can update
+    let msg = ExecuteMsg::UpdateRewardStream {
+        token: "partner0000".to_string(),
+        distribution_schedule: vec![
+            (12345 + 50, 12345 + 150, Uint128::from(1000u128)),
+            (12345 + 200, 12345 + 300, Uint128::from(1000u128)),
+        ],
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("addr0000", &[]), msg);
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("Must return unauthorized error"),
+    }
+
+    let msg = ExecuteMsg::UpdateRewardStream {
+        token: "partner0001".to_string(),
+        distribution_schedule: vec![],
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg);
+    match res {
+        Err(ContractError::RewardStreamNotFound {}) => (),
+        _ => panic!("Must return reward stream not found error"),
+    }
+
+    // extend the stream beyond the pylon schedule
+    let msg = ExecuteMsg::UpdateRewardStream {
+        token: "partner0000".to_string(),
+        distribution_schedule: vec![
+            (12345 + 50, 12345 + 150, Uint128::from(1000u128)),
+            (12345 + 200, 12345 + 300, Uint128::from(1000u128)),
+        ],
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "update_reward_stream"),
+            attr("token", "partner0000"),
+        ]
+    );
+
+    // pylon rewards are paid out as the schedule ends
+    env.block.height += 50;
+    for staker in ["addr0000", "addr0001"] {
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(staker, &[]),
+            ExecuteMsg::Withdraw { recipient: None },
+        )
+        .unwrap();
+    }
+
+    // 100 blocks passed
+    // only 1,000 partner rewards distributed, split in half
+    env.block.height += 100;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::Withdraw { recipient: None },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "partner0000".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "addr0000".to_string(),
+                amount: Uint128::from(500u128),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+
+    // extra rewards are withdrawn even without pylon rewards to stake
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::WithdrawAndStakeGov {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "partner0000".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "addr0001".to_string(),
+                amount: Uint128::from(500u128),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::WithdrawAndStakeGov {},
+    );
+    match res {
+        Err(ContractError::NoPendingReward {}) => (),
+        _ => panic!("Must return no pending reward error"),
+    }
+
+    // schedule a range and stop the stream before it starts
+    let msg = ExecuteMsg::UpdateRewardStream {
+        token: "partner0000".to_string(),
+        distribution_schedule: vec![
+            (12345 + 50, 12345 + 150, Uint128::from(1000u128)),
+            (12345 + 200, 12345 + 300, Uint128::from(1000u128)),
+            (12345 + 400, 12345 + 500, Uint128::from(1000u128)),
+        ],
+    };
+    let _res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg).unwrap();
+    let msg = ExecuteMsg::UpdateRewardStream {
+        token: "partner0000".to_string(),
+        distribution_schedule: vec![
+            (12345 + 50, 12345 + 150, Uint128::from(1000u128)),
+            (12345 + 200, 12345 + 300, Uint128::from(1000u128)),
+        ],
+    };
+    let _res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg).unwrap();
+
+    // nothing left to withdraw
+    env.block.height += 300;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::Withdraw { recipient: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages, vec![]);
+}
+
+#[test]
+fn test_time_schedule() {
+    let mut deps = mock_dependencies(&[]);
+
+    let genesis = mock_env().block.time.seconds();
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![
+            (genesis, genesis + 100, Uint128::from(1000000u128)),
+            (genesis + 100, genesis + 200, Uint128::from(10000000u128)),
+        ],
+        schedule_mode: Some(ScheduleMode::Time),
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let state: StateResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::State {
+                token_version: None,
+                block_height: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(state.started_at, genesis);
+    assert_eq!(state.last_distributed, genesis);
+
+    // bond 100 tokens
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "addr0000".to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+    });
+    let info = mock_info("staking0000", &[]);
+    let mut env = mock_env();
+    let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // blocks without time passing distribute nothing
+    env.block.height += 100;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::Withdraw { recipient: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages, vec![]);
+
+    // 150 seconds passed
+    // 1,000,000 + 5,000,000 rewards distributed
+    env.block.time = env.block.time.plus_seconds(150);
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("addr0000", &[]),
+        ExecuteMsg::Withdraw { recipient: None },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "reward0000".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "addr0000".to_string(),
+                amount: Uint128::from(6000000u128),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+}
+
+#[test]
+fn test_migrate_bond() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![
+            (12345, 12345 + 100, Uint128::from(1000000u128)),
+            (12345 + 100, 12345 + 200, Uint128::from(10000000u128)),
+        ],
+        schedule_mode: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let asset_infos = [
+        AssetInfo::Token {
+            contract_addr: "reward0000".to_string(),
+        },
+        AssetInfo::Token {
+            contract_addr: "partner0000".to_string(),
+        },
+    ];
+    deps.querier.with_minters(&[
+        (&"staking0000".to_string(), &"pair0000".to_string()),
+        (&"staking0001".to_string(), &"pair0001".to_string()),
+    ]);
+    deps.querier.with_terraswap_pairs(&[
+        (
+            &"pair0000".to_string(),
+            &asset_infos,
+            &"staking0000".to_string(),
+        ),
+        (
+            &"pair0001".to_string(),
+            &asset_infos,
+            &"staking0001".to_string(),
+        ),
+    ]);
+
+    // bond 100 tokens
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "addr0000".to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+    });
+    let mut env = mock_env();
+    let _res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("staking0000", &[]),
+        msg,
+    )
+    .unwrap();
+
+    // 10 blocks passed, 100,000 rewards distributed
+    env.block.height += 10;
+    let msg = ExecuteMsg::MigrateStaking {
+        new_staking_contract: "staking0001".to_string(),
+    };
+    let _res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg).unwrap();
+
+    // nothing to migrate
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::MigrateBond {
+            slippage_tolerance: None,
+            min_lp_amount: None,
+        },
+    );
+    match res {
+        Err(ContractError::NoLegacyBond {}) => (),
+        _ => panic!("Must return no legacy bond error"),
+    }
+
+    // contract already holds pylon rewards and LP tokens of other stakers
+    deps.querier.with_token_balances(&[
+        (
+            &"reward0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(1000u128))],
+        ),
+        (
+            &"staking0001".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(50u128))],
+        ),
+    ]);
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigrateBond {
+            slippage_tolerance: Some(Decimal::percent(1)),
+            min_lp_amount: Some(Uint128::from(80u128)),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::reply_on_success(
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "staking0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: "pair0000".to_string(),
+                    amount: Uint128::from(100u128),
+                    msg: to_binary(&TerraswapCw20HookMsg::WithdrawLiquidity {}).unwrap(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            1,
+        )]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "migrate_bond"),
+            attr("owner", "addr0000"),
+            attr("amount", "100"),
+        ]
+    );
+
+    // liquidity withdrawn
+    deps.querier.with_token_balances(&[
+        (
+            &"reward0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(1300u128))],
+        ),
+        (
+            &"partner0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(200u128))],
+        ),
+        (
+            &"staking0001".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(50u128))],
+        ),
+    ]);
+
+    let res = reply(deps.as_mut(), env.clone(), reply_msg(1)).unwrap();
+    let assets = [
+        Asset {
+            info: asset_infos[0].clone(),
+            amount: Uint128::from(300u128),
+        },
+        Asset {
+            info: asset_infos[1].clone(),
+            amount: Uint128::from(200u128),
+        },
+    ];
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "reward0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                    spender: "pair0001".to_string(),
+                    amount: Uint128::from(300u128),
+                    expires: None,
+                })
+                .unwrap(),
+                funds: vec![],
+            })),
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "partner0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                    spender: "pair0001".to_string(),
+                    amount: Uint128::from(200u128),
+                    expires: None,
+                })
+                .unwrap(),
+                funds: vec![],
+            })),
+            SubMsg::reply_on_success(
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: "pair0001".to_string(),
+                    msg: to_binary(&TerraswapExecuteMsg::ProvideLiquidity {
+                        assets,
+                        slippage_tolerance: Some(Decimal::percent(1)),
+                        receiver: None,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }),
+                2,
+            ),
+        ]
+    );
+
+    // less liquidity than the minimum
+    deps.querier.with_token_balances(&[(
+        &"staking0001".to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(129u128))],
+    )]);
+    match reply(deps.as_mut(), env.clone(), reply_msg(2)) {
+        Err(ContractError::InsufficientLiquidity {}) => (),
+        _ => panic!("Must return insufficient liquidity error"),
+    }
+
+    // liquidity provided
+    deps.querier.with_token_balances(&[(
+        &"staking0001".to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(130u128))],
+    )]);
+
+    let res = reply(deps.as_mut(), env.clone(), reply_msg(2)).unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "bond"),
+            attr("owner", "addr0000"),
+            attr("amount", "80"),
+        ]
+    );
+
+    // rewards of the legacy bond are kept
+    assert_eq!(
+        from_binary::<StakerInfoResponse>(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::StakerInfo {
+                    staker: "addr0000".to_string(),
+                    block_height: None,
+                },
+            )
+            .unwrap()
+        )
+        .unwrap(),
+        StakerInfoResponse {
+            staker: "addr0000".to_string(),
+            staking_token_version: 1,
+            reward_index: Decimal::zero(),
+            bond_amount: Uint128::from(80u128),
+            working_amount: Uint128::from(80u128),
+            pending_reward: Uint128::from(100000u128),
+            pending_rewards: vec![("reward0000".to_string(), Uint128::from(100000u128))],
+        }
+    );
+
+    let state: StateResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            env,
+            QueryMsg::State {
+                token_version: Some(0),
+                block_height: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(state.total_bond_amount, Uint128::zero());
+}
+
+fn reply_msg(id: u64) -> Reply {
+    Reply {
+        id,
+        result: ContractResult::Ok(SubMsgExecutionResponse {
+            events: vec![],
+            data: None,
+        }),
+    }
+}
+
+#[test]
+fn test_staker_infos_and_stats() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![
+            (12345, 12345 + 100, Uint128::from(1000000u128)),
+            (12345 + 100, 12345 + 200, Uint128::from(10000000u128)),
+        ],
+        schedule_mode: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let mut env = mock_env();
+    for (staker, amount) in [("addr0000", 100u128), ("addr0001", 100), ("addr0002", 300)] {
+        let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: staker.to_string(),
+            amount: Uint128::from(amount),
+            msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+        });
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("staking0000", &[]),
+            msg,
+        )
+        .unwrap();
+    }
+
+    // 50 blocks passed
+    // 500,000 rewards distributed, not settled yet
+    env.block.height += 50;
+    let stakers = from_binary::<StakerInfosResponse>(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::StakerInfos {
+                start_after: None,
+                limit: None,
+                order_by: Some(OrderBy::Asc),
+                token_version: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+    .stakers;
+    let mut pending_rewards = stakers
+        .iter()
+        .map(|x| (x.staker.clone(), x.pending_reward))
+        .collect::<Vec<(String, Uint128)>>();
+    pending_rewards.sort();
+    assert_eq!(
+        pending_rewards,
+        vec![
+            ("addr0000".to_string(), Uint128::from(100000u128)),
+            ("addr0001".to_string(), Uint128::from(100000u128)),
+            ("addr0002".to_string(), Uint128::from(300000u128)),
+        ]
+    );
+
+    // paginate
+    let page = from_binary::<StakerInfosResponse>(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::StakerInfos {
+                start_after: Some(stakers[0].staker.clone()),
+                limit: Some(1),
+                order_by: Some(OrderBy::Asc),
+                token_version: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+    .stakers;
+    assert_eq!(page, vec![stakers[1].clone()]);
+
+    let page = from_binary::<StakerInfosResponse>(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::StakerInfos {
+                start_after: Some(stakers[2].staker.clone()),
+                limit: None,
+                order_by: Some(OrderBy::Desc),
+                token_version: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+    .stakers;
+    assert_eq!(page, vec![stakers[1].clone(), stakers[0].clone()]);
+
+    // migrate to a new staking token and bond to it
+    let msg = ExecuteMsg::MigrateStaking {
+        new_staking_contract: "staking0001".to_string(),
+    };
+    let _res = execute(deps.as_mut(), env.clone(), mock_info("gov0000", &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "addr0003".to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+    });
+    let _res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("staking0001", &[]),
+        msg,
+    )
+    .unwrap();
+
+    let stakers = from_binary::<StakerInfosResponse>(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::StakerInfos {
+                start_after: None,
+                limit: None,
+                order_by: None,
+                token_version: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+    .stakers;
+    assert_eq!(
+        stakers
+            .iter()
+            .map(|x| x.staker.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["addr0003"]
+    );
+
+    // a filtered page scans no more than the limit
+    let mut start_after = None;
+    let mut pages = vec![];
+    loop {
+        let page = from_binary::<StakerInfosResponse>(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::StakerInfos {
+                    start_after,
+                    limit: Some(1),
+                    order_by: Some(OrderBy::Asc),
+                    token_version: Some(1),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        pages.push(page.stakers);
+        start_after = match page.last_staker {
+            Some(last_staker) => Some(last_staker),
+            None => break,
+        };
+    }
+    assert_eq!(pages.len(), 5);
+    assert_eq!(
+        pages
+            .concat()
+            .iter()
+            .map(|x| x.staker.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["addr0003"]
+    );
+
+    // 50 blocks passed
+    // 500,000 rewards distributed to the new staking token
+    env.block.height += 50;
+    let stats = |start_after: Option<&str>, limit: Option<u32>| {
+        from_binary::<StatsResponse>(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Stats {
+                    start_after: start_after.map(|x| x.to_string()),
+                    limit,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+    assert_eq!(
+        stats(None, None),
+        StatsResponse {
+            num_stakers: 4,
+            last_staker: Some("addr0003".to_string()),
+            total_pending_rewards: vec![("reward0000".to_string(), Uint128::from(1000000u128))],
+            remaining_emissions: vec![("reward0000".to_string(), Uint128::from(10000000u128))],
+        }
+    );
+
+    // pages add up to the totals
+    let first = stats(None, Some(3));
+    assert_eq!(first.num_stakers, 3);
+    assert_eq!(first.last_staker, Some("addr0002".to_string()));
+    let second = stats(first.last_staker.as_deref(), Some(3));
+    assert_eq!(second.num_stakers, 1);
+    assert_eq!(second.last_staker, Some("addr0003".to_string()));
+    assert_eq!(
+        first.total_pending_rewards[0].1 + second.total_pending_rewards[0].1,
+        Uint128::from(1000000u128)
+    );
+    assert_eq!(stats(Some("addr0003"), None).last_staker, None);
+}
+
+#[test]
+fn test_withdraw_to_recipient() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![(12345, 12345 + 100, Uint128::from(1000000u128))],
+        schedule_mode: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // bond 100 tokens
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "addr0000".to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+    });
+    let mut env = mock_env();
+    let _res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("staking0000", &[]),
+        msg,
+    )
+    .unwrap();
+
+    // 100 blocks passed
+    // 1,000,000 rewards distributed
+    env.block.height += 100;
+    let msg = ExecuteMsg::Withdraw {
+        recipient: Some("addr0001".to_string()),
+    };
+    let res = execute(deps.as_mut(), env, mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "reward0000".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "addr0001".to_string(),
+                amount: Uint128::from(1000000u128),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "withdraw"),
+            attr("owner", "addr0000"),
+            attr("amount", "1000000"),
+            attr("recipient", "addr0001"),
+        ]
+    );
+}
+
+#[test]
+fn test_withdraw_and_stake_gov() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![(12345, 12345 + 100, Uint128::from(1000000u128))],
+        schedule_mode: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // bond 100 tokens
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "addr0000".to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+    });
+    let mut env = mock_env();
+    let _res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("staking0000", &[]),
+        msg,
+    )
+    .unwrap();
+
+    // nothing distributed yet
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::WithdrawAndStakeGov {},
+    );
+    match res {
+        Err(ContractError::NoPendingReward {}) => (),
+        _ => panic!("Must return no pending reward error"),
+    }
+
+    // 100 blocks passed
+    // 1,000,000 rewards distributed
+    env.block.height += 100;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::WithdrawAndStakeGov {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "reward0000".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Send {
+                contract: "gov0000".to_string(),
+                amount: Uint128::from(1000000u128),
+                msg: to_binary(&GovCw20HookMsg::Stake {
+                    staker: Some("addr0000".to_string()),
+                })
+                .unwrap(),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "withdraw_and_stake_gov"),
+            attr("owner", "addr0000"),
+            attr("amount", "1000000"),
+        ]
+    );
+
+    // rewards were taken out
+    assert_eq!(
+        from_binary::<StakerInfoResponse>(
+            &query(
+                deps.as_ref(),
+                env,
+                QueryMsg::StakerInfo {
+                    staker: "addr0000".to_string(),
+                    block_height: None,
+                },
+            )
+            .unwrap()
+        )
+        .unwrap()
+        .pending_reward,
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn test_unbonding_period() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![(12345, 12345 + 100, Uint128::from(1000000u128))],
+        schedule_mode: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // only governance can update
+    let msg = ExecuteMsg::UpdateConfig {
+        distribution_schedule: None,
+        unbonding_period: Some(10),
+        boost_weight: None,
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg);
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("Must return unauthorized error"),
+    }
+
+    let msg = ExecuteMsg::UpdateConfig {
+        distribution_schedule: None,
+        unbonding_period: Some(10),
+        boost_weight: None,
+    };
+    let _res = execute(deps.as_mut(), mock_env(), mock_info("gov0000", &[]), msg).unwrap();
+
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    let config: ConfigResponse = from_binary(&res).unwrap();
+    assert_eq!(config.unbonding_period, 10);
+
+    // bond 100 tokens
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "addr0000".to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+    });
+    let mut env = mock_env();
+    let _res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("staking0000", &[]),
+        msg,
+    )
+    .unwrap();
+
+    // unbond is queued instead of transferred
+    let msg = ExecuteMsg::Unbond {
+        amount: Uint128::from(60u128),
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(res.messages, vec![]);
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "unbond"),
+            attr("owner", "addr0000"),
+            attr("amount", "60"),
+            attr("release_at", (12345 + 10).to_string()),
+        ]
+    );
+
+    env.block.height += 5;
+    let msg = ExecuteMsg::Unbond {
+        amount: Uint128::from(40u128),
+    };
+    let _res = execute(deps.as_mut(), env.clone(), mock_info("addr0000", &[]), msg).unwrap();
+
+    // nothing matured yet
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::ClaimUnbonded {},
+    );
+    match res {
+        Err(ContractError::NoMaturedUnbonding {}) => (),
+        _ => panic!("Must return no matured unbonding error"),
+    }
+
+    // first unbonding matured
+    env.block.height += 5;
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::Unbondings {
+            staker: "addr0000".to_string(),
+        },
+    )
+    .unwrap();
+    let unbondings: UnbondingsResponse = from_binary(&res).unwrap();
+    assert_eq!(
+        unbondings,
+        UnbondingsResponse {
+            unbondings: vec![
+                UnbondingResponse {
+                    staking_token_version: 0,
+                    amount: Uint128::from(60u128),
+                    release_at: 12345 + 10,
+                },
+                UnbondingResponse {
+                    staking_token_version: 0,
+                    amount: Uint128::from(40u128),
+                    release_at: 12345 + 15,
+                },
+            ],
+            claimable_amount: Uint128::from(60u128),
+        }
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::ClaimUnbonded {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "staking0000".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "addr0000".to_string(),
+                amount: Uint128::from(60u128),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "claim_unbonded"),
+            attr("owner", "addr0000"),
+            attr("amount", "60"),
+        ]
+    );
+
+    // remaining one matured
+    env.block.height += 5;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::ClaimUnbonded {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "claim_unbonded"),
+            attr("owner", "addr0000"),
+            attr("amount", "40"),
+        ]
+    );
+
+    let res = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::Unbondings {
+            staker: "addr0000".to_string(),
+        },
+    )
+    .unwrap();
+    let unbondings: UnbondingsResponse = from_binary(&res).unwrap();
+    assert_eq!(
+        unbondings,
+        UnbondingsResponse {
+            unbondings: vec![],
+            claimable_amount: Uint128::zero(),
+        }
+    );
+}
+
+#[test]
+fn test_boost() {
+    let mut deps = mock_dependencies(&[]);
+    deps.querier
+        .with_gov_stakers(&[(&"addr0000".to_string(), &Uint128::from(100u128))]);
+
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![(12345, 12345 + 100, Uint128::from(1000000u128))],
+        schedule_mode: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // boost weight cannot exceed 1
+    let msg = ExecuteMsg::UpdateConfig {
+        distribution_schedule: None,
+        unbonding_period: None,
+        boost_weight: Some(Decimal::percent(101)),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("gov0000", &[]), msg);
+    match res {
+        Err(ContractError::InvalidBoostWeight {}) => (),
+        _ => panic!("Must return invalid boost weight error"),
+    }
+
+    let msg = ExecuteMsg::UpdateConfig {
+        distribution_schedule: None,
+        unbonding_period: None,
+        boost_weight: Some(Decimal::percent(60)),
+    };
+    let _res = execute(deps.as_mut(), mock_env(), mock_info("gov0000", &[]), msg).unwrap();
+
+    // addr0000 stakes all of gov, addr0001 stakes nothing
+    let mut env = mock_env();
+    for staker in ["addr0000", "addr0001"] {
+        let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: staker.to_string(),
+            amount: Uint128::from(100u128),
+            msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+        });
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("staking0000", &[]),
+            msg,
+        )
+        .unwrap();
+    }
+
+    // addr0000: min(100, 100 * 0.4 + 100 * 0.6 * 100 / 100) = 100
+    // addr0001: min(100, 100 * 0.4 + 200 * 0.6 * 0 / 100) = 40
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::State {
+            token_version: None,
+            block_height: None,
+        },
+    )
+    .unwrap();
+    let state: StateResponse = from_binary(&res).unwrap();
+    assert_eq!(state.total_bond_amount, Uint128::from(200u128));
+    assert_eq!(state.total_working_amount, Uint128::from(140u128));
+
+    // 70 blocks passed
+    // 700,000 rewards distributed by working amount
+    env.block.height += 70;
+    let query_staker = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, staker: &str, height: u64| {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::StakerInfo {
+                staker: staker.to_string(),
+                block_height: Some(height),
+            },
+        )
+        .unwrap();
+        let staker_info: StakerInfoResponse = from_binary(&res).unwrap();
+        (staker_info.working_amount, staker_info.pending_reward)
+    };
+    assert_eq!(
+        query_staker(&deps, "addr0000", env.block.height),
+        (Uint128::from(100u128), Uint128::from(500000u128))
+    );
+    assert_eq!(
+        query_staker(&deps, "addr0001", env.block.height),
+        (Uint128::from(40u128), Uint128::from(200000u128))
+    );
+
+    // gov stake moved from addr0000 to addr0001
+    deps.querier
+        .with_gov_stakers(&[(&"addr0001".to_string(), &Uint128::from(100u128))]);
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0002", &[]),
+        ExecuteMsg::Kick {
+            staker: "addr0000".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "kick"),
+            attr("staker", "addr0000"),
+            attr("working_amount", "40"),
+        ]
+    );
+
+    let _res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0002", &[]),
+        ExecuteMsg::Kick {
+            staker: "addr0001".to_string(),
+        },
+    )
+    .unwrap();
+
+    // staker without bond cannot be kicked
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0002", &[]),
+        ExecuteMsg::Kick {
+            staker: "addr0002".to_string(),
+        },
+    );
+    match res {
+        Err(ContractError::NoBondToKick {}) => (),
+        _ => panic!("Must return no bond to kick error"),
+    }
+
+    // 30 blocks passed
+    // 300,000 rewards distributed, 2142.857142857142857142 per working amount
+    env.block.height += 30;
+    assert_eq!(
+        query_staker(&deps, "addr0000", env.block.height),
+        (Uint128::from(40u128), Uint128::from(585714u128))
+    );
+    assert_eq!(
+        query_staker(&deps, "addr0001", env.block.height),
+        (Uint128::from(100u128), Uint128::from(414285u128))
+    );
+
+    // gov stake of addr0000 increased, kick applies the higher boost
+    deps.querier.with_gov_stakers(&[
+        (&"addr0000".to_string(), &Uint128::from(300u128)),
+        (&"addr0001".to_string(), &Uint128::from(100u128)),
+    ]);
+
+    // addr0000: min(100, 100 * 0.4 + 200 * 0.6 * 300 / 400) = 100
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0002", &[]),
+        ExecuteMsg::Kick {
+            staker: "addr0000".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "kick"),
+            attr("staker", "addr0000"),
+            attr("working_amount", "100"),
+        ]
+    );
+
+    // unbond recomputes the boost instead of only capping it by the bond
+    // addr0001: min(50, 50 * 0.4 + 150 * 100 / 400 * 0.6) = 42
+    let _res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::Unbond {
+            amount: Uint128::from(50u128),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        query_staker(&deps, "addr0001", env.block.height).0,
+        Uint128::from(42u128)
+    );
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::State {
+            token_version: None,
+            block_height: None,
+        },
+    )
+    .unwrap();
+    let state: StateResponse = from_binary(&res).unwrap();
+    assert_eq!(state.total_bond_amount, Uint128::from(150u128));
+    assert_eq!(state.total_working_amount, Uint128::from(142u128));
+}
+
+#[test]
+fn test_boost_disabled() {
+    // gov queries fail, as no gov stakers are configured
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![(12345, 12345 + 100, Uint128::from(1000000u128))],
+        schedule_mode: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // zero boost weight skips the gov queries, working amount is the bond
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "addr0000".to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+    });
+    let _res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("staking0000", &[]),
+        msg,
+    )
+    .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::Kick {
+            staker: "addr0000".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "kick"),
+            attr("staker", "addr0000"),
+            attr("working_amount", "100"),
+        ]
+    );
+
+    // once boosted, the same kick needs the gov queries
+    let msg = ExecuteMsg::UpdateConfig {
+        distribution_schedule: None,
+        unbonding_period: None,
+        boost_weight: Some(Decimal::percent(60)),
+    };
+    let _res = execute(deps.as_mut(), mock_env(), mock_info("gov0000", &[]), msg).unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::Kick {
+            staker: "addr0000".to_string(),
+        },
+    );
+    match res {
+        Err(ContractError::Std(StdError::GenericErr { .. })) => (),
+        _ => panic!("Must return querier error"),
+    }
+}
+
+#[test]
+fn test_emergency_unbond() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![(12345, 12345 + 100, Uint128::from(1000000u128))],
+        schedule_mode: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // bond 100 tokens each
+    let mut env = mock_env();
+    for staker in ["addr0000", "addr0001"] {
+        let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: staker.to_string(),
+            amount: Uint128::from(100u128),
+            msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+        });
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("staking0000", &[]),
+            msg,
+        )
+        .unwrap();
+    }
+
+    // 50 blocks passed, pending rewards are forfeited
+    env.block.height += 50;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::EmergencyUnbond {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "staking0000".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "addr0000".to_string(),
+                amount: Uint128::from(100u128),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "emergency_unbond"),
+            attr("owner", "addr0000"),
+            attr("amount", "100"),
+        ]
+    );
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::StakerInfo {
+            staker: "addr0000".to_string(),
+            block_height: Some(env.block.height),
+        },
+    )
+    .unwrap();
+    let staker_info: StakerInfoResponse = from_binary(&res).unwrap();
+    assert_eq!(staker_info.bond_amount, Uint128::zero());
+    assert_eq!(staker_info.pending_reward, Uint128::zero());
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::State {
+            token_version: None,
+            block_height: None,
+        },
+    )
+    .unwrap();
+    let state: StateResponse = from_binary(&res).unwrap();
+    assert_eq!(state.total_bond_amount, Uint128::from(100u128));
+    assert_eq!(state.total_working_amount, Uint128::from(100u128));
+
+    // forfeited rewards are not redistributed to the remaining staker
+    // 250,000 for the first 50 blocks and 500,000 for the rest
+    for (height, pending_reward) in [(env.block.height, 250000u128), (12345 + 100, 750000u128)] {
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::StakerInfo {
+                staker: "addr0001".to_string(),
+                block_height: Some(height),
+            },
+        )
+        .unwrap();
+        let staker_info: StakerInfoResponse = from_binary(&res).unwrap();
+        assert_eq!(staker_info.pending_reward, Uint128::from(pending_reward));
+    }
+
+    // nothing left to unbond
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("addr0000", &[]),
+        ExecuteMsg::EmergencyUnbond {},
+    );
+    match res {
+        Err(ContractError::NoBondToUnbond {}) => (),
+        _ => panic!("Must return no bond to unbond error"),
+    }
+
+    // reward computation overflows once the schedule starts
+    let mut deps = mock_dependencies(&[]);
+    let msg = InstantiateMsg {
+        governance: "gov0000".to_string(),
+        pylon_token: "reward0000".to_string(),
+        staking_token: "staking0000".to_string(),
+        distribution_schedule: vec![(12345 + 10, 12345 + 20, Uint128::MAX)],
+        schedule_mode: None,
+    };
+    let _res = instantiate(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "addr0000".to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+    });
+    let mut env = mock_env();
+    let _res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("staking0000", &[]),
+        msg,
+    )
+    .unwrap();
+
+    env.block.height += 15;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::Unbond {
+            amount: Uint128::from(100u128),
+        },
+    );
+    match res {
+        Err(ContractError::Std(StdError::ConversionOverflow { .. })) => (),
+        _ => panic!("Must return conversion overflow error"),
+    }
+
+    // the principal is still returned
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("addr0000", &[]),
+        ExecuteMsg::EmergencyUnbond {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "staking0000".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "addr0000".to_string(),
+                amount: Uint128::from(100u128),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+}
@@ -10,7 +10,8 @@ use crate::state::{
     read_config, read_vesting_info, read_vesting_infos, store_config, store_vesting_info, Config,
 };
 use cw20::Cw20ExecuteMsg;
-use pylon_token::common::OrderBy;
+use pylon_token::common::{OrderBy, PendingOwnerResponse};
+use pylon_token::ownership::Ownership;
 use pylon_token::vesting::{
     ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg, VestingAccount, VestingAccountResponse,
     VestingAccountsResponse, VestingInfo,
@@ -27,6 +28,7 @@ pub fn instantiate(
         deps.storage,
         &Config {
             owner: deps.api.addr_canonicalize(&msg.owner)?,
+            pending_owner: None,
             pylon_token: deps.api.addr_canonicalize(&msg.pylon_token)?,
             genesis_time: msg.genesis_time,
         },
@@ -39,14 +41,18 @@ pub fn instantiate(
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
     match msg {
         ExecuteMsg::Claim {} => claim(deps, env, info),
+        ExecuteMsg::AcceptOwnership {} => accept_ownership(deps, env, info),
         _ => {
-            assert_owner_privilege(deps.storage, deps.api, info.sender)?;
+            assert_owner_privilege(deps.storage, deps.api, info.sender.clone())?;
             match msg {
                 ExecuteMsg::UpdateConfig {
-                    owner,
                     pylon_token,
                     genesis_time,
-                } => update_config(deps, owner, pylon_token, genesis_time),
+                } => update_config(deps, pylon_token, genesis_time),
+                ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
+                    propose_new_owner(deps, env, info, owner, expires_in)
+                }
+                ExecuteMsg::CancelOwnershipProposal {} => cancel_ownership_proposal(deps, info),
                 ExecuteMsg::RegisterVestingAccounts { vesting_accounts } => {
                     register_vesting_accounts(deps, vesting_accounts)
                 }
@@ -66,15 +72,10 @@ fn assert_owner_privilege(storage: &dyn Storage, api: &dyn Api, sender: Addr) ->
 
 pub fn update_config(
     deps: DepsMut,
-    owner: Option<String>,
     pylon_token: Option<String>,
     genesis_time: Option<u64>,
 ) -> StdResult<Response> {
     let mut config = read_config(deps.storage)?;
-    if let Some(owner) = owner {
-        config.owner = deps.api.addr_canonicalize(&owner)?;
-    }
-
     if let Some(pylon_token) = pylon_token {
         config.pylon_token = deps.api.addr_canonicalize(&pylon_token)?;
     }
@@ -88,6 +89,51 @@ pub fn update_config(
     Ok(Response::new().add_attributes(vec![("action", "update_config")]))
 }
 
+pub fn propose_new_owner(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    expires_in: Option<u64>,
+) -> StdResult<Response> {
+    let mut config = read_config(deps.storage)?;
+    let response = Ownership {
+        owner: &mut config.owner,
+        pending_owner: &mut config.pending_owner,
+    }
+    .propose(deps.api, &env, &info, owner, expires_in)?;
+
+    store_config(deps.storage, &config)?;
+
+    Ok(response)
+}
+
+pub fn accept_ownership(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Response> {
+    let mut config = read_config(deps.storage)?;
+    let response = Ownership {
+        owner: &mut config.owner,
+        pending_owner: &mut config.pending_owner,
+    }
+    .accept(deps.api, &env, &info)?;
+
+    store_config(deps.storage, &config)?;
+
+    Ok(response)
+}
+
+pub fn cancel_ownership_proposal(deps: DepsMut, info: MessageInfo) -> StdResult<Response> {
+    let mut config = read_config(deps.storage)?;
+    let response = Ownership {
+        owner: &mut config.owner,
+        pending_owner: &mut config.pending_owner,
+    }
+    .cancel(deps.api, &info)?;
+
+    store_config(deps.storage, &config)?;
+
+    Ok(response)
+}
+
 fn assert_vesting_schedules(vesting_schedules: &[(u64, u64, Uint128)]) -> StdResult<()> {
     for vesting_schedule in vesting_schedules.iter() {
         if vesting_schedule.0 >= vesting_schedule.1 {
@@ -200,6 +246,13 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     let state = read_config(deps.storage)?;
     let resp = ConfigResponse {
         owner: deps.api.addr_humanize(&state.owner)?.to_string(),
+        pending_owner: match state.pending_owner {
+            Some(pending_owner) => Some(PendingOwnerResponse {
+                owner: deps.api.addr_humanize(&pending_owner.owner)?.to_string(),
+                expires_at: pending_owner.expires_at,
+            }),
+            None => None,
+        },
         pylon_token: deps.api.addr_humanize(&state.pylon_token)?.to_string(),
         genesis_time: state.genesis_time,
     };
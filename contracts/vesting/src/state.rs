@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use cosmwasm_std::{CanonicalAddr, StdResult, Storage};
 use cosmwasm_storage::{bucket, bucket_read, singleton, singleton_read, ReadonlyBucket};
 use pylon_token::common::OrderBy;
+use pylon_token::ownership::PendingOwner;
 use pylon_token::vesting::VestingInfo;
 
 const KEY_CONFIG: &[u8] = b"config";
@@ -12,6 +13,7 @@ const PREFIX_KEY_VESTING_INFO: &[u8] = b"vesting_info";
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
     pub owner: CanonicalAddr,
+    pub pending_owner: Option<PendingOwner>,
     pub pylon_token: CanonicalAddr,
     pub genesis_time: u64,
 }
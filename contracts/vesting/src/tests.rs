@@ -1,5 +1,5 @@
 use crate::contract::{execute, instantiate, query};
-use pylon_token::common::OrderBy;
+use pylon_token::common::{OrderBy, PendingOwnerResponse};
 use pylon_token::vesting::{
     ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg, VestingAccount, VestingAccountResponse,
     VestingAccountsResponse, VestingInfo,
@@ -32,6 +32,7 @@ fn proper_initialization() {
         .unwrap(),
         ConfigResponse {
             owner: "owner".to_string(),
+            pending_owner: None,
             pylon_token: "pylon_token".to_string(),
             genesis_time: 12345u64,
         }
@@ -52,10 +53,16 @@ fn update_config() {
     let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
     let msg = ExecuteMsg::UpdateConfig {
-        owner: Some("owner2".to_string()),
-        pylon_token: None,
-        genesis_time: None,
+        pylon_token: Some("pylon_token2".to_string()),
+        genesis_time: Some(1u64),
     };
+    let info = mock_info("owner2", &[]);
+    let res = execute(deps.as_mut(), mock_env(), info, msg.clone());
+    match res {
+        Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "unauthorized"),
+        _ => panic!("DO NOT ENTER HERE"),
+    }
+
     let info = mock_info("owner", &[]);
     let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
@@ -65,31 +72,89 @@ fn update_config() {
         )
         .unwrap(),
         ConfigResponse {
-            owner: "owner2".to_string(),
+            owner: "owner".to_string(),
+            pending_owner: None,
+            pylon_token: "pylon_token2".to_string(),
+            genesis_time: 1u64,
+        }
+    );
+}
+
+#[test]
+fn transfer_ownership() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        owner: "owner".to_string(),
+        pylon_token: "pylon_token".to_string(),
+        genesis_time: 12345u64,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let msg = ExecuteMsg::ProposeNewOwner {
+        owner: "owner2".to_string(),
+        expires_in: Some(100u64),
+    };
+    let info = mock_info("owner2", &[]);
+    let res = execute(deps.as_mut(), mock_env(), info, msg.clone());
+    match res {
+        Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "unauthorized"),
+        _ => panic!("DO NOT ENTER HERE"),
+    }
+
+    let info = mock_info("owner", &[]);
+    let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let env = mock_env();
+    assert_eq!(
+        from_binary::<ConfigResponse>(
+            &query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap()
+        )
+        .unwrap(),
+        ConfigResponse {
+            owner: "owner".to_string(),
+            pending_owner: Some(PendingOwnerResponse {
+                owner: "owner2".to_string(),
+                expires_at: Some(env.block.time.seconds() + 100u64),
+            }),
             pylon_token: "pylon_token".to_string(),
             genesis_time: 12345u64,
         }
     );
 
-    let msg = ExecuteMsg::UpdateConfig {
-        owner: Some("owner".to_string()),
-        pylon_token: None,
-        genesis_time: None,
-    };
+    // only the pending owner can accept
     let info = mock_info("owner", &[]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::AcceptOwnership {},
+    );
     match res {
         Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "unauthorized"),
         _ => panic!("DO NOT ENTER HERE"),
     }
 
-    let msg = ExecuteMsg::UpdateConfig {
-        owner: None,
-        pylon_token: Some("pylon_token2".to_string()),
-        genesis_time: Some(1u64),
-    };
+    // proposal expired
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(101u64);
     let info = mock_info("owner2", &[]);
-    let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    let res = execute(deps.as_mut(), env, info, ExecuteMsg::AcceptOwnership {});
+    match res {
+        Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "ownership proposal expired"),
+        _ => panic!("DO NOT ENTER HERE"),
+    }
+
+    let info = mock_info("owner2", &[]);
+    let _res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::AcceptOwnership {},
+    )
+    .unwrap();
 
     assert_eq!(
         from_binary::<ConfigResponse>(
@@ -98,12 +163,67 @@ fn update_config() {
         .unwrap(),
         ConfigResponse {
             owner: "owner2".to_string(),
-            pylon_token: "pylon_token2".to_string(),
-            genesis_time: 1u64,
+            pending_owner: None,
+            pylon_token: "pylon_token".to_string(),
+            genesis_time: 12345u64,
         }
     );
 }
 
+#[test]
+fn cancel_ownership_proposal() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        owner: "owner".to_string(),
+        pylon_token: "pylon_token".to_string(),
+        genesis_time: 12345u64,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let info = mock_info("owner", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::CancelOwnershipProposal {},
+    );
+    match res {
+        Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "no pending owner"),
+        _ => panic!("DO NOT ENTER HERE"),
+    }
+
+    let msg = ExecuteMsg::ProposeNewOwner {
+        owner: "owner2".to_string(),
+        expires_in: None,
+    };
+    let info = mock_info("owner", &[]);
+    let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let info = mock_info("owner", &[]);
+    let _res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::CancelOwnershipProposal {},
+    )
+    .unwrap();
+
+    let info = mock_info("owner2", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::AcceptOwnership {},
+    );
+    match res {
+        Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "no pending owner"),
+        _ => panic!("DO NOT ENTER HERE"),
+    }
+}
+
 #[test]
 fn register_vesting_accounts() {
     let mut deps = mock_dependencies(&[]);
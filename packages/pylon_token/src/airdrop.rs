@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 
 use cosmwasm_std::Uint128;
 
+use crate::common::PendingOwnerResponse;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
     pub owner: String,
@@ -12,9 +14,13 @@ pub struct InstantiateMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-    UpdateConfig {
-        owner: Option<String>,
+    /// Proposes a new owner, who has to accept the ownership before it expires
+    ProposeNewOwner {
+        owner: String,
+        expires_in: Option<u64>, // seconds
     },
+    AcceptOwnership {},
+    CancelOwnershipProposal {},
     RegisterMerkleRoot {
         merkle_root: String,
     },
@@ -43,6 +49,7 @@ pub enum QueryMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ConfigResponse {
     pub owner: String,
+    pub pending_owner: Option<PendingOwnerResponse>,
     pub pylon_token: String,
 }
 
@@ -19,3 +19,9 @@ impl From<OrderBy> for Order {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingOwnerResponse {
+    pub owner: String,
+    pub expires_at: Option<u64>, // unix seconds
+}
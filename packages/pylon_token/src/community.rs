@@ -2,6 +2,8 @@ use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::common::PendingOwnerResponse;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
     pub gov_contract: String, // pylon gov contract
@@ -12,8 +14,20 @@ pub struct InstantiateMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-    UpdateConfig { spend_limit: Option<Uint128> },
-    Spend { recipient: String, amount: Uint128 },
+    UpdateConfig {
+        spend_limit: Option<Uint128>,
+    },
+    Spend {
+        recipient: String,
+        amount: Uint128,
+    },
+    /// Proposes a new gov contract, which has to accept the ownership before it expires
+    ProposeNewOwner {
+        owner: String,
+        expires_in: Option<u64>, // seconds
+    },
+    AcceptOwnership {},
+    CancelOwnershipProposal {},
 }
 
 /// We currently take no arguments for migrations
@@ -30,6 +44,7 @@ pub enum QueryMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ConfigResponse {
     pub gov_contract: String,
+    pub pending_gov_contract: Option<PendingOwnerResponse>,
     pub pylon_token: String,
     pub spend_limit: Uint128,
 }
@@ -3,6 +3,7 @@ use cw20::Cw20ReceiveMsg;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use terraswap::asset::AssetInfo;
 
 use crate::common::OrderBy;
 
@@ -61,9 +62,24 @@ pub enum AirdropMsg {
     Instantiate {
         start: u64,
         period: u64,
-        reward_token: String,
+        reward_token: AssetInfo,
         reward_amount: Uint128,
     },
+    /// Adds reward to an ongoing airdrop, spread over its remaining period
+    TopUp {
+        airdrop_id: u64,
+        amount: Uint128,
+    },
+    /// Lengthens an ongoing airdrop, spreading the remaining reward over the new period
+    Extend {
+        airdrop_id: u64,
+        additional_period: u64,
+    },
+    /// Stops an ongoing airdrop and refunds the undistributed reward, sender by default
+    Cancel {
+        airdrop_id: u64,
+        refund_to: Option<String>,
+    },
     Allocate {
         airdrop_id: u64,
         recipient: String,
@@ -74,15 +90,32 @@ pub enum AirdropMsg {
         recipient: String,
         deallocate_amount: Uint128,
     },
+    /// Allocate for many recipients at once, given as (recipient, amount)
+    AllocateBatch {
+        airdrop_id: u64,
+        allocations: Vec<(String, Uint128)>,
+    },
+    /// Deallocate for many recipients at once, given as (recipient, amount)
+    DeallocateBatch {
+        airdrop_id: u64,
+        deallocations: Vec<(String, Uint128)>,
+    },
     Update {
         target: Option<String>,
     },
+    /// Prunes finished airdrops from the update candidates, callable by anyone
+    Compact {},
+    /// Claims rewards of the given airdrops, or every airdrop with reward if not given.
+    /// recipient can only be set when claiming for oneself
     Claim {
         target: Option<String>,
+        airdrop_ids: Option<Vec<u64>>,
+        recipient: Option<String>,
     },
     ClaimInternal {
         sender: String,
         airdrop_id: u64,
+        recipient: Option<String>,
     },
 }
 
@@ -91,7 +124,6 @@ pub enum AirdropMsg {
 pub enum ExecuteMsg {
     Receive(Cw20ReceiveMsg),
     UpdateConfig {
-        owner: Option<String>,
         quorum: Option<Decimal>,
         threshold: Option<Decimal>,
         voting_period: Option<u64>,
@@ -99,6 +131,13 @@ pub enum ExecuteMsg {
         proposal_deposit: Option<Uint128>,
         snapshot_period: Option<u64>,
     },
+    /// Proposes a new owner, who has to accept the ownership before it expires
+    ProposeNewOwner {
+        owner: String,
+        expires_in: Option<u64>, // seconds
+    },
+    AcceptOwnership {},
+    CancelOwnershipProposal {},
     Poll(PollMsg),
     Staking(StakingMsg),
     Airdrop(AirdropMsg),
@@ -109,7 +148,10 @@ pub enum ExecuteMsg {
 pub enum Cw20HookMsg {
     /// StakeVotingTokens a user can stake their mirror token to receive rewards
     /// or do vote on polls
-    Stake {},
+    Stake {
+        /// stake on behalf of the given address, cw20 sender by default
+        staker: Option<String>,
+    },
     /// CreatePoll need to receive deposit from a proposer
     CreatePoll {
         title: String,
@@ -117,7 +159,11 @@ pub enum Cw20HookMsg {
         description: String,
         link: Option<String>,
         execute_msgs: Option<Vec<PollExecuteMsg>>,
+        /// Turns the poll into a multiple-choice poll, execute_msgs must be empty
+        multiple_choice: Option<PollMultipleChoiceMsg>,
     },
+    /// Deposit voting tokens for airdrops, which are excluded from the staked balance
+    FundAirdrop {},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -128,12 +174,46 @@ pub struct PollExecuteMsg {
     pub msg: Binary,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PollTallyMode {
+    /// Voters pick one option, the option with the most votes wins
+    /// if its share of the votes is over the threshold
+    Plurality,
+    /// Voters approve any number of options, the most approved option wins
+    /// if its approval ratio is over the threshold
+    Approval,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PollOptionMsg {
+    pub name: String,
+    pub execute_msgs: Option<Vec<PollExecuteMsg>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PollMultipleChoiceMsg {
+    pub mode: PollTallyMode,
+    pub options: Vec<PollOptionMsg>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     ApiVersion {},
     Config {},
+    ConfigHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    },
     State {},
+    /// Balance per share checkpoints recorded on stake/unstake, keyed by block time
+    ShareRateHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    },
     Staker {
         address: String,
     },
@@ -150,9 +230,25 @@ pub enum QueryMsg {
         limit: Option<u32>,
         order_by: Option<OrderBy>,
     },
+    /// Expected reward of the staker at the given time, assuming the current total share.
+    /// at_time before the current block is treated as now
+    AirdropProjection {
+        address: String,
+        airdrop_id: u64,
+        at_time: u64,
+    },
+    AirdropAllocations {
+        airdrop_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    },
     Poll {
         poll_id: u64,
     },
+    PollTally {
+        poll_id: u64,
+    },
     Polls {
         status_filter: Option<PollStatus>,
         category_filter: Option<PollCategory>,
@@ -189,6 +285,7 @@ pub enum PollStatus {
     Executed,
     Expired, // Deprecated
     Failed,
+    Concluded, // Passed signal poll, nothing to execute
 }
 
 impl fmt::Display for PollStatus {
@@ -216,14 +313,24 @@ impl fmt::Display for PollCategory {
 pub enum VoteOption {
     Yes,
     No,
+    /// Indexes of the chosen options in a multiple-choice poll
+    Options(Vec<u32>),
 }
 
 impl fmt::Display for VoteOption {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if *self == VoteOption::Yes {
-            write!(f, "yes")
-        } else {
-            write!(f, "no")
+        match self {
+            VoteOption::Yes => write!(f, "yes"),
+            VoteOption::No => write!(f, "no"),
+            VoteOption::Options(options) => write!(
+                f,
+                "{}",
+                options
+                    .iter()
+                    .map(|option| option.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
         }
     }
 }
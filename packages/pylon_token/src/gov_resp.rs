@@ -1,9 +1,12 @@
 use cosmwasm_std::{Decimal, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use terraswap::asset::AssetInfo;
 
+use crate::common::PendingOwnerResponse;
 use crate::gov_msg::{
-    ClaimableAirdrop, PollCategory, PollExecuteMsg, PollStatus, VoteOption, VoterInfo,
+    ClaimableAirdrop, PollCategory, PollExecuteMsg, PollStatus, PollTallyMode, VoteOption,
+    VoterInfo,
 };
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
@@ -14,6 +17,7 @@ pub struct APIVersionResponse {
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
 pub struct ConfigResponse {
     pub owner: String,
+    pub pending_owner: Option<PendingOwnerResponse>,
     pub pylon_token: String,
     pub quorum: Decimal,
     pub threshold: Decimal,
@@ -23,6 +27,27 @@ pub struct ConfigResponse {
     pub snapshot_period: u64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct ConfigFieldChangeResponse {
+    pub field: String,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct ConfigChangeResponse {
+    pub id: u64,
+    pub sender: String,
+    pub height: u64,
+    pub poll_id: Option<u64>,
+    pub changes: Vec<ConfigFieldChangeResponse>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct ConfigHistoryResponse {
+    pub history: Vec<ConfigChangeResponse>,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
 pub struct StateResponse {
     pub poll_count: u64,
@@ -30,6 +55,24 @@ pub struct StateResponse {
     pub total_deposit: Uint128,
     pub total_airdrop_count: u64,
     pub airdrop_update_candidates: Vec<u64>,
+    pub airdrop_update_candidate_count: u64,
+    /// staked tokens, excluding poll deposits
+    pub total_balance: Uint128,
+    pub balance_per_share: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct ShareRateCheckpointResponse {
+    pub time: u64,
+    pub height: u64,
+    pub balance: Uint128,
+    pub share: Uint128,
+    pub balance_per_share: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct ShareRateHistoryResponse {
+    pub checkpoints: Vec<ShareRateCheckpointResponse>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
@@ -48,14 +91,32 @@ pub struct PollResponse {
     pub no_votes: Uint128,  // balance
     pub staked_amount: Option<Uint128>,
     pub total_balance_at_end_poll: Option<Uint128>,
+    pub multiple_choice: Option<PollMultipleChoiceResponse>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct PollOptionResponse {
+    pub name: String,
+    pub execute_data: Option<Vec<PollExecuteMsg>>,
+    pub votes: Uint128, // balance
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct PollMultipleChoiceResponse {
+    pub mode: PollTallyMode,
+    pub options: Vec<PollOptionResponse>,
+    pub voted_amount: Uint128, // balance
+    pub winning_option: Option<u32>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
 pub struct AirdropResponse {
     pub start: u64,
     pub period: u64,
-    pub reward_token: String,
+    pub reward_token: AssetInfo,
     pub reward_rate: Decimal,
+    pub funded: Uint128,
+    pub distributed: Uint128,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
@@ -63,6 +124,48 @@ pub struct AirdropsResponse {
     pub airdrops: Vec<(u64, AirdropResponse)>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct AirdropProjectionResponse {
+    pub airdrop_id: u64,
+    pub reward_token: AssetInfo,
+    pub at_time: u64,
+    pub share: Uint128,
+    pub total_share: Uint128,
+    pub reward: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct AirdropAllocationsResponseItem {
+    pub recipient: String,
+    pub amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct AirdropAllocationsResponse {
+    pub allocations: Vec<AirdropAllocationsResponseItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct PollTallyResponse {
+    pub poll_id: u64,
+    pub status: PollStatus,
+    pub staked_weight: Uint128,
+    pub participation: Decimal,
+    pub yes_ratio: Decimal,
+    pub quorum: Decimal,
+    pub threshold: Decimal,
+    pub quorum_reached: bool,
+    pub threshold_reached: bool,
+    /// Leading option of a multiple-choice poll, None on a tie
+    pub winning_option: Option<u32>,
+    /// Outcome if the poll were ended at the current block (actual outcome once ended)
+    pub projected_status: PollStatus,
+    pub blocks_until_end: u64,
+    pub blocks_until_timelock_end: u64,
+    /// None if the poll is not expected to pass or has already been executed
+    pub blocks_until_executable: Option<u64>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
 pub struct PollsResponse {
     pub polls: Vec<PollResponse>,
@@ -5,5 +5,6 @@ pub mod community;
 pub mod distributor;
 pub mod gov_msg;
 pub mod gov_resp;
+pub mod ownership;
 pub mod staking;
 pub mod vesting;
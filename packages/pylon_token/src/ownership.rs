@@ -0,0 +1,244 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use cosmwasm_std::{Api, CanonicalAddr, Env, MessageInfo, Response, StdError};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingOwner {
+    pub owner: CanonicalAddr,
+    pub expires_at: Option<u64>, // unix seconds
+}
+
+#[derive(Debug, PartialEq)]
+pub enum OwnershipError {
+    Std(StdError),
+    Unauthorized,
+    NoPendingOwner,
+    OwnershipProposalExpired,
+    InvalidExpiry,
+}
+
+impl fmt::Display for OwnershipError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OwnershipError::Std(e) => write!(f, "{}", e),
+            OwnershipError::Unauthorized => write!(f, "unauthorized"),
+            OwnershipError::NoPendingOwner => write!(f, "no pending owner"),
+            OwnershipError::OwnershipProposalExpired => write!(f, "ownership proposal expired"),
+            OwnershipError::InvalidExpiry => write!(f, "expiry out of range"),
+        }
+    }
+}
+
+impl From<StdError> for OwnershipError {
+    fn from(e: StdError) -> Self {
+        OwnershipError::Std(e)
+    }
+}
+
+impl From<OwnershipError> for StdError {
+    fn from(e: OwnershipError) -> Self {
+        match e {
+            OwnershipError::Std(e) => e,
+            e => StdError::generic_err(e.to_string()),
+        }
+    }
+}
+
+/// Two-step ownership transfer over the owner and the pending owner of a contract config
+pub struct Ownership<'a> {
+    pub owner: &'a mut CanonicalAddr,
+    pub pending_owner: &'a mut Option<PendingOwner>,
+}
+
+impl<'a> Ownership<'a> {
+    pub fn propose(
+        self,
+        api: &dyn Api,
+        env: &Env,
+        info: &MessageInfo,
+        owner: String,
+        expires_in: Option<u64>,
+    ) -> Result<Response, OwnershipError> {
+        self.assert_owner(api, info)?;
+
+        let expires_at = match expires_in {
+            Some(expires_in) => Some(
+                env.block
+                    .time
+                    .seconds()
+                    .checked_add(expires_in)
+                    .ok_or(OwnershipError::InvalidExpiry)?,
+            ),
+            None => None,
+        };
+        *self.pending_owner = Some(PendingOwner {
+            owner: api.addr_canonicalize(&owner)?,
+            expires_at,
+        });
+
+        Ok(Response::new().add_attributes(vec![
+            ("action", "propose_new_owner"),
+            ("pending_owner", &owner),
+            (
+                "expires_at",
+                &expires_at.map(|x| x.to_string()).unwrap_or_default(),
+            ),
+        ]))
+    }
+
+    pub fn accept(
+        self,
+        api: &dyn Api,
+        env: &Env,
+        info: &MessageInfo,
+    ) -> Result<Response, OwnershipError> {
+        let pending_owner = match self.pending_owner {
+            Some(pending_owner) => pending_owner,
+            None => return Err(OwnershipError::NoPendingOwner),
+        };
+
+        if pending_owner.owner != api.addr_canonicalize(info.sender.as_str())? {
+            return Err(OwnershipError::Unauthorized);
+        }
+
+        if let Some(expires_at) = pending_owner.expires_at {
+            if expires_at < env.block.time.seconds() {
+                return Err(OwnershipError::OwnershipProposalExpired);
+            }
+        }
+
+        *self.owner = pending_owner.owner.clone();
+        *self.pending_owner = None;
+
+        Ok(Response::new().add_attributes(vec![
+            ("action", "accept_ownership"),
+            ("owner", info.sender.as_str()),
+        ]))
+    }
+
+    pub fn cancel(self, api: &dyn Api, info: &MessageInfo) -> Result<Response, OwnershipError> {
+        self.assert_owner(api, info)?;
+
+        if self.pending_owner.is_none() {
+            return Err(OwnershipError::NoPendingOwner);
+        }
+
+        *self.pending_owner = None;
+
+        Ok(Response::new().add_attributes(vec![("action", "cancel_ownership_proposal")]))
+    }
+
+    fn assert_owner(&self, api: &dyn Api, info: &MessageInfo) -> Result<(), OwnershipError> {
+        if *self.owner != api.addr_canonicalize(info.sender.as_str())? {
+            return Err(OwnershipError::Unauthorized);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::attr;
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi};
+
+    #[test]
+    fn transfer_ownership() {
+        let api = MockApi::default();
+        let env = mock_env();
+        let mut owner = api.addr_canonicalize("owner0000").unwrap();
+        let mut pending_owner = None;
+        macro_rules! ownership {
+            () => {
+                Ownership {
+                    owner: &mut owner,
+                    pending_owner: &mut pending_owner,
+                }
+            };
+        }
+
+        // only the owner can propose or cancel
+        let info = mock_info("addr0000", &[]);
+        assert_eq!(
+            ownership!().propose(&api, &env, &info, "addr0000".to_string(), None),
+            Err(OwnershipError::Unauthorized)
+        );
+        assert_eq!(
+            ownership!().cancel(&api, &info),
+            Err(OwnershipError::Unauthorized)
+        );
+        assert_eq!(
+            ownership!().accept(&api, &env, &info),
+            Err(OwnershipError::NoPendingOwner)
+        );
+
+        let info = mock_info("owner0000", &[]);
+        assert_eq!(
+            ownership!().propose(&api, &env, &info, "addr0000".to_string(), Some(u64::MAX)),
+            Err(OwnershipError::InvalidExpiry)
+        );
+        assert_eq!(
+            ownership!().cancel(&api, &info),
+            Err(OwnershipError::NoPendingOwner)
+        );
+
+        let res = ownership!()
+            .propose(&api, &env, &info, "addr0000".to_string(), Some(100))
+            .unwrap();
+        let expires_at = env.block.time.seconds() + 100;
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "propose_new_owner"),
+                attr("pending_owner", "addr0000"),
+                attr("expires_at", expires_at.to_string()),
+            ]
+        );
+        assert_eq!(
+            pending_owner,
+            Some(PendingOwner {
+                owner: api.addr_canonicalize("addr0000").unwrap(),
+                expires_at: Some(expires_at),
+            })
+        );
+
+        // only the pending owner can accept before expiry
+        assert_eq!(
+            ownership!().accept(&api, &env, &mock_info("addr0001", &[])),
+            Err(OwnershipError::Unauthorized)
+        );
+        let mut expired = env.clone();
+        expired.block.time = expired.block.time.plus_seconds(101);
+        assert_eq!(
+            ownership!().accept(&api, &expired, &mock_info("addr0000", &[])),
+            Err(OwnershipError::OwnershipProposalExpired)
+        );
+
+        // cancelled proposal cannot be accepted
+        ownership!().cancel(&api, &info).unwrap();
+        assert_eq!(pending_owner, None);
+        assert_eq!(
+            ownership!().accept(&api, &env, &mock_info("addr0000", &[])),
+            Err(OwnershipError::NoPendingOwner)
+        );
+
+        ownership!()
+            .propose(&api, &env, &info, "addr0000".to_string(), None)
+            .unwrap();
+        let res = ownership!()
+            .accept(&api, &expired, &mock_info("addr0000", &[]))
+            .unwrap();
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "accept_ownership"),
+                attr("owner", "addr0000"),
+            ]
+        );
+        assert_eq!(owner, api.addr_canonicalize("addr0000").unwrap());
+        assert_eq!(pending_owner, None);
+    }
+}
@@ -4,12 +4,33 @@ use serde::{Deserialize, Serialize};
 use cosmwasm_std::{Decimal, Uint128};
 use cw20::Cw20ReceiveMsg;
 
+use crate::common::OrderBy;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
     pub governance: String,
     pub pylon_token: String,
     pub staking_token: String, // lp token of ANC-UST pair contract
     pub distribution_schedule: Vec<(u64, u64, Uint128)>,
+    pub schedule_mode: Option<ScheduleMode>, // defaults to height
+}
+
+/// Unit of the distribution schedule ranges
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleMode {
+    /// (start_height, end_height, amount)
+    Height,
+    /// (start_time, end_time, amount) in unix seconds
+    Time,
+}
+
+// #[default] on variants is not available on the toolchain of workspace-optimizer
+#[allow(clippy::derivable_impls)]
+impl Default for ScheduleMode {
+    fn default() -> Self {
+        ScheduleMode::Height
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -19,11 +40,45 @@ pub enum ExecuteMsg {
     Unbond {
         amount: Uint128,
     },
-    /// Withdraw pending rewards
-    Withdraw {},
+    /// Unbond the whole bond without computing rewards, forfeiting pending rewards
+    EmergencyUnbond {},
+    /// Withdraw pending rewards to recipient, sender by default
+    Withdraw {
+        recipient: Option<String>,
+    },
+    /// Withdraw pending rewards, staking pylon token to gov on behalf of sender
+    WithdrawAndStakeGov {},
+    /// Re-provide legacy bond to the latest pair and bond the resulting LP token
+    MigrateBond {
+        slippage_tolerance: Option<Decimal>,
+        min_lp_amount: Option<Uint128>,
+    },
     MigrateStaking {
         new_staking_contract: String,
     },
+    /// Replace the distribution schedule, the unbonding period or the boost weight (governance only)
+    UpdateConfig {
+        distribution_schedule: Option<Vec<(u64, u64, Uint128)>>,
+        unbonding_period: Option<u64>,
+        boost_weight: Option<Decimal>,
+    },
+    /// Transfer unbonded staking tokens whose unbonding period has passed
+    ClaimUnbonded {},
+    /// Recompute the boosted working amount of the staker from its current gov stake
+    Kick {
+        staker: String,
+    },
+    /// Distribute an additional reward token alongside pylon token (governance only)
+    AddRewardStream {
+        token: String,
+        distribution_schedule: Vec<(u64, u64, Uint128)>,
+    },
+    /// Replace the distribution schedule of an extra reward stream,
+    /// dropping the ranges not started yet stops it (governance only)
+    UpdateRewardStream {
+        token: String,
+        distribution_schedule: Vec<(u64, u64, Uint128)>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -36,22 +91,47 @@ pub enum Cw20HookMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MigrateMsg {
-    Migrate { governance: String },
+    Migrate {
+        governance: String,
+    },
     General {},
+    /// Convert height based schedules to unix seconds,
+    /// assuming the given average block time for the range boundaries
+    TimeSchedule {
+        seconds_per_block: Decimal,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     Config {},
+    /// block_height is a unix timestamp in time schedule mode
     State {
         token_version: Option<u64>,
         block_height: Option<u64>,
     },
+    /// block_height is a unix timestamp in time schedule mode
     StakerInfo {
         staker: String,
         block_height: Option<u64>,
     },
+    /// Stakers with their rewards computed up to now
+    StakerInfos {
+        start_after: Option<String>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+        token_version: Option<u64>,
+    },
+    /// Reward liabilities over a page of stakers as of now, to be summed over pages
+    Stats {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Unbonding queue of the staker
+    Unbondings {
+        staker: String,
+    },
 }
 
 // We define a custom struct for each query response
@@ -60,6 +140,16 @@ pub struct ConfigResponse {
     pub pylon_token: String,
     pub staking_token: Vec<String>,
     pub distribution_schedule: Vec<(u64, u64, Uint128)>,
+    pub schedule_mode: ScheduleMode,
+    pub unbonding_period: u64, // in the unit of the schedule mode
+    pub boost_weight: Decimal,
+    pub extra_rewards: Vec<RewardStreamResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RewardStreamResponse {
+    pub token: String,
+    pub distribution_schedule: Vec<(u64, u64, Uint128)>,
 }
 
 // We define a custom struct for each query response
@@ -69,6 +159,7 @@ pub struct StateResponse {
     pub started_at: u64,
     pub last_distributed: u64,
     pub total_bond_amount: Uint128,
+    pub total_working_amount: Uint128,
     pub global_reward_index: Decimal,
 }
 
@@ -79,5 +170,34 @@ pub struct StakerInfoResponse {
     pub staking_token_version: u64,
     pub reward_index: Decimal,
     pub bond_amount: Uint128,
+    pub working_amount: Uint128, // bond amount weighted by gov stake
     pub pending_reward: Uint128,
+    pub pending_rewards: Vec<(String, Uint128)>, // (reward token, amount) including pylon token
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakerInfosResponse {
+    pub stakers: Vec<StakerInfoResponse>, // may be fewer than the limit if filtered by token version
+    pub last_staker: Option<String>,      // start_after of the next page, none at the end
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StatsResponse {
+    pub num_stakers: u64,                              // in the page
+    pub last_staker: Option<String>,                   // start_after of the next page
+    pub total_pending_rewards: Vec<(String, Uint128)>, // (reward token, amount) including pylon token
+    pub remaining_emissions: Vec<(String, Uint128)>,   // (reward token, amount) scheduled after now
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct UnbondingsResponse {
+    pub unbondings: Vec<UnbondingResponse>,
+    pub claimable_amount: Uint128, // matured as of now, over all token versions
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct UnbondingResponse {
+    pub staking_token_version: u64,
+    pub amount: Uint128,
+    pub release_at: u64, // in the unit of the schedule mode
 }
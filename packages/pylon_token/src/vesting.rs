@@ -2,7 +2,7 @@ use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::common::OrderBy;
+use crate::common::{OrderBy, PendingOwnerResponse};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
@@ -15,10 +15,16 @@ pub struct InstantiateMsg {
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
     UpdateConfig {
-        owner: Option<String>,
         pylon_token: Option<String>,
         genesis_time: Option<u64>,
     },
+    /// Proposes a new owner, who has to accept the ownership before it expires
+    ProposeNewOwner {
+        owner: String,
+        expires_in: Option<u64>, // seconds
+    },
+    AcceptOwnership {},
+    CancelOwnershipProposal {},
     RegisterVestingAccounts {
         vesting_accounts: Vec<VestingAccount>,
     },
@@ -56,6 +62,7 @@ pub enum QueryMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ConfigResponse {
     pub owner: String,
+    pub pending_owner: Option<PendingOwnerResponse>,
     pub pylon_token: String,
     pub genesis_time: u64,
 }